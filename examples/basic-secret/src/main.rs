@@ -2,27 +2,36 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use lazy_static::lazy_static;
 
 use octocat_rs::{
     handler::EventHandler,
     rest::{methods::get_user_keys, model::repositories::events::PingEvent},
-    Client, ClientBuilder, Command,
+    Client, ClientBuilder, Command, WebhookSecrets,
 };
 
-lazy_static! {
-    static ref WEBHOOK_SECRET: String = std::env::var("WEBHOOK_SECRET").unwrap();
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
-    ClientBuilder::new().event_handler(Handler {}).build()?.start().await;
+    let mut secrets = WebhookSecrets::from_env("WEBHOOK_SECRET")?;
+
+    // Keep accepting deliveries signed with the previous secret while rotating.
+    // An empty value is ignored rather than disabling verification.
+    if let Ok(previous) = std::env::var("PREVIOUS_WEBHOOK_SECRET") {
+        secrets = secrets.with_secret(previous);
+    }
+
+    ClientBuilder::new()
+        .event_handler(Handler { secrets })
+        .build()?
+        .start()
+        .await;
 
     Ok(())
 }
 
 #[derive(Debug)]
-struct Handler {}
+struct Handler {
+    secrets: WebhookSecrets,
+}
 
 #[derive(Debug)]
 enum Message {
@@ -38,8 +47,8 @@ impl EventHandler for Handler {
         2022
     }
 
-    fn listener_secret(&self) -> &'static [u8] {
-        WEBHOOK_SECRET.as_bytes()
+    fn listener_secrets(&self) -> WebhookSecrets {
+        self.secrets.clone()
     }
 
     async fn message(&self, message: Self::Message) {
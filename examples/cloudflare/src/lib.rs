@@ -17,10 +17,9 @@ pub async fn main(req: Request, env: Env, _ctx: worker::Context) -> worker::Resu
             // TODO: Don't construct this every time
             let client = ClientBuilder::new().event_handler(Handler {}).build().unwrap();
 
-            match client.handle(req).await {
-                Some((msg, code)) => Response::error(msg, code),
-                None => Response::empty(),
-            }
+            let (body, status) = client.handle(req).await;
+
+            Ok(Response::ok(body)?.with_status(status))
         })
         .get("/worker-version", |_, ctx| {
             let version = ctx.var("WORKERS_RS_VERSION")?.to_string();
@@ -0,0 +1,259 @@
+use crate::{
+    builders::{
+        builder, builder_nested_setters, builder_nested_string_setters, builder_nested_string_setters_required,
+        builder_setters, builder_string_setters, Builder,
+    },
+    methods::{
+        dispatch_workflow, get_artifacts, get_workflow_run_artifacts, get_workflow_run_jobs, get_workflow_runs,
+        get_workflow_runs_for_workflow, rerun_workflow_run, DispatchWorkflowBody, GetArtifactsBody, GetJobsBody,
+        GetWorkflowRunsBody, RerunWorkflowRunBody,
+    },
+    model::actions::{Artifacts, Jobs, WorkflowRuns},
+    GithubRestError, Requester,
+};
+use async_trait::async_trait;
+
+builder!(
+    /// * tags actions
+    /// * post `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches`
+    /// * docs <https://docs.github.com/rest/reference/actions#create-a-workflow-dispatch-event>
+    ///
+    /// Create a workflow dispatch event
+    /// You can use this endpoint to manually trigger a GitHub Actions
+    /// workflow run. You can replace `workflow_id` with the workflow file
+    /// name. For example, you could use `main.yaml`.
+    DispatchWorkflowBuilder {
+        owner: String,
+        repo: String,
+        workflow_id: String,
+        body: DispatchWorkflowBody
+    }
+);
+
+builder_string_setters!(DispatchWorkflowBuilder {
+    owner,
+    repo,
+    workflow_id
+});
+builder_nested_string_setters_required!(DispatchWorkflowBuilder { body { reference } });
+
+impl DispatchWorkflowBuilder {
+    /// Sets an input configured in the workflow file.
+    pub fn input<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.body.inputs.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for DispatchWorkflowBuilder {
+    type Response = ();
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        dispatch_workflow(client, self.owner, self.repo, self.workflow_id, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags actions
+    /// * get `/repos/{owner}/{repo}/actions/runs`
+    /// * docs <https://docs.github.com/rest/reference/actions#list-workflow-runs-for-a-repository>
+    ///
+    /// List workflow runs for a repository
+    /// Lists all workflow runs for a repository, or only the runs of a
+    /// workflow if `workflow_id` is set. You can use parameters to narrow
+    /// the list of results.
+    GetWorkflowRunsBuilder {
+        owner: String,
+        repo: String,
+        workflow_id: Option<String>,
+        body: GetWorkflowRunsBody
+    }
+);
+
+builder_string_setters!(GetWorkflowRunsBuilder { owner, repo });
+builder_nested_string_setters!(GetWorkflowRunsBuilder {
+    body {
+        actor,
+        branch,
+        event,
+        status,
+        created,
+        head_sha,
+        per_page,
+        page
+    }
+});
+
+impl GetWorkflowRunsBuilder {
+    /// Only lists the runs of the given workflow. This can be its ID or its
+    /// file name, e.g. `main.yaml`.
+    pub fn workflow_id<T: Into<String>>(mut self, workflow_id: T) -> Self {
+        self.workflow_id = Some(workflow_id.into());
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for GetWorkflowRunsBuilder {
+    type Response = WorkflowRuns;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        match self.workflow_id {
+            Some(workflow_id) => {
+                get_workflow_runs_for_workflow(client, self.owner, self.repo, workflow_id, Some(&self.body)).await
+            }
+            None => get_workflow_runs(client, self.owner, self.repo, Some(&self.body)).await,
+        }
+    }
+}
+
+builder!(
+    /// * tags actions
+    /// * post `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun`
+    /// * docs <https://docs.github.com/rest/reference/actions#re-run-a-workflow>
+    ///
+    /// Re-run a workflow
+    /// Re-runs your workflow run using its `id`.
+    RerunWorkflowRunBuilder {
+        owner: String,
+        repo: String,
+        run_id: i64,
+        body: RerunWorkflowRunBody
+    }
+);
+
+builder_string_setters!(RerunWorkflowRunBuilder { owner, repo });
+builder_setters!(RerunWorkflowRunBuilder { run_id: i64 });
+builder_nested_setters!(RerunWorkflowRunBuilder { body { enable_debug_logging: bool } });
+
+#[async_trait]
+impl Builder for RerunWorkflowRunBuilder {
+    type Response = ();
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        rerun_workflow_run(client, self.owner, self.repo, self.run_id, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags actions
+    /// * get `/repos/{owner}/{repo}/actions/runs/{run_id}/jobs`
+    /// * docs <https://docs.github.com/rest/reference/actions#list-jobs-for-a-workflow-run>
+    ///
+    /// List jobs for a workflow run
+    /// Lists jobs for a workflow run. You can use parameters to narrow the
+    /// list of results.
+    GetWorkflowRunJobsBuilder {
+        owner: String,
+        repo: String,
+        run_id: i64,
+        body: GetJobsBody
+    }
+);
+
+builder_string_setters!(GetWorkflowRunJobsBuilder { owner, repo });
+builder_setters!(GetWorkflowRunJobsBuilder { run_id: i64 });
+builder_nested_string_setters!(GetWorkflowRunJobsBuilder { body { filter, per_page, page } });
+
+#[async_trait]
+impl Builder for GetWorkflowRunJobsBuilder {
+    type Response = Jobs;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        get_workflow_run_jobs(client, self.owner, self.repo, self.run_id, Some(&self.body)).await
+    }
+}
+
+builder!(
+    /// * tags actions
+    /// * get `/repos/{owner}/{repo}/actions/artifacts`
+    /// * docs <https://docs.github.com/rest/reference/actions#list-artifacts-for-a-repository>
+    ///
+    /// List artifacts for a repository
+    /// Lists all artifacts for a repository, or only the artifacts of a
+    /// workflow run if `run_id` is set.
+    GetArtifactsBuilder {
+        owner: String,
+        repo: String,
+        run_id: Option<i64>,
+        body: GetArtifactsBody
+    }
+);
+
+builder_string_setters!(GetArtifactsBuilder { owner, repo });
+builder_nested_string_setters!(GetArtifactsBuilder { body { name, per_page, page } });
+
+impl GetArtifactsBuilder {
+    /// Only lists the artifacts of the given workflow run.
+    pub fn run_id(mut self, run_id: i64) -> Self {
+        self.run_id = Some(run_id);
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for GetArtifactsBuilder {
+    type Response = Artifacts;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        match self.run_id {
+            Some(run_id) => get_workflow_run_artifacts(client, self.owner, self.repo, run_id, Some(&self.body)).await,
+            None => get_artifacts(client, self.owner, self.repo, Some(&self.body)).await,
+        }
+    }
+}
+
+#[cfg(all(feature = "builders", feature = "client"))]
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        end_points::EndPoints,
+        mock::{Interaction, MockRequester},
+    };
+
+    #[tokio::test]
+    async fn test_dispatch_workflow_builder() {
+        let endpoint = EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "release.yml".to_owned(),
+        );
+
+        let client = MockRequester::new().with(
+            Interaction::new(endpoint)
+                .body(&json!({ "ref": "main", "inputs": { "version": "0.2.0" } }))
+                .status(204),
+        );
+
+        DispatchWorkflowBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .workflow_id("release.yml")
+            .reference("main")
+            .input("version", "0.2.0")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(client.requests().len(), 1);
+    }
+}
@@ -0,0 +1,377 @@
+use crate::{
+    builders::{
+        builder, builder_nested_setters, builder_nested_string_setters, builder_setters, builder_string_setters,
+        Builder,
+    },
+    methods::{
+        create_check_run, update_check_run, CheckRunActionBody, CheckRunAnnotationBody, CheckRunImageBody,
+        CheckRunOutputBody, CreateCheckRunBody, UpdateCheckRunBody,
+    },
+    model::{
+        checks::{CheckRunConclusion, CheckRunStatus},
+        repositories::workflows::events::nested::CheckRun,
+    },
+    GithubRestError, Requester,
+};
+use async_trait::async_trait;
+
+/// Build an impl block with setters for the `output` and `actions` of a check
+/// run body.
+macro_rules! check_run_output_setters {
+    ($name:ident) => {
+        impl $name {
+            fn output_mut(&mut self) -> &mut CheckRunOutputBody {
+                self.body.output.get_or_insert_with(Default::default)
+            }
+
+            /// Sets the title and summary of the output.
+            pub fn summary<T: Into<String>, S: Into<String>>(mut self, title: T, summary: S) -> Self {
+                let output = self.output_mut();
+                output.title = title.into();
+                output.summary = summary.into();
+                self
+            }
+
+            /// Sets the details of the output. This parameter supports
+            /// Markdown.
+            pub fn text<T: Into<String>>(mut self, text: T) -> Self {
+                self.output_mut().text = Some(text.into());
+                self
+            }
+
+            /// Adds an annotation to the output. There is no limit on the
+            /// number of annotations, see
+            /// [`MAX_ANNOTATIONS_PER_REQUEST`](crate::methods::MAX_ANNOTATIONS_PER_REQUEST).
+            pub fn annotation(mut self, annotation: CheckRunAnnotationBody) -> Self {
+                self.output_mut().annotations.push(annotation);
+                self
+            }
+
+            /// Adds several annotations to the output.
+            pub fn annotations<I: IntoIterator<Item = CheckRunAnnotationBody>>(mut self, annotations: I) -> Self {
+                self.output_mut().annotations.extend(annotations);
+                self
+            }
+
+            /// Adds an image to the output.
+            pub fn image(mut self, image: CheckRunImageBody) -> Self {
+                self.output_mut().images.push(image);
+                self
+            }
+
+            /// Adds a button to the check run. GitHub accepts up to 3.
+            pub fn action<L: Into<String>, D: Into<String>, I: Into<String>>(
+                mut self,
+                label: L,
+                description: D,
+                identifier: I,
+            ) -> Self {
+                self.body.actions.push(CheckRunActionBody {
+                    label: label.into(),
+                    description: description.into(),
+                    identifier: identifier.into(),
+                });
+                self
+            }
+        }
+    };
+}
+
+builder!(
+    /// * tags checks
+    /// * post `/repos/{owner}/{repo}/check-runs`
+    /// * docs <https://docs.github.com/rest/reference/checks#create-a-check-run>
+    ///
+    /// Create a check run
+    /// Creates a new check run for a specific commit in a repository. Your
+    /// GitHub App must have the `checks:write` permission to create check
+    /// runs.
+    CheckRunBuilder {
+        owner: String,
+        repo: String,
+        body: CreateCheckRunBody
+    }
+);
+
+builder_string_setters!(CheckRunBuilder { owner, repo });
+builder_nested_string_setters!(CheckRunBuilder {
+    body {
+        details_url,
+        external_id,
+        started_at,
+        completed_at
+    }
+});
+builder_nested_setters!(CheckRunBuilder {
+    body {
+        status: CheckRunStatus,
+        conclusion: CheckRunConclusion,
+        output: CheckRunOutputBody
+    }
+});
+check_run_output_setters!(CheckRunBuilder);
+
+impl CheckRunBuilder {
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.body.name = name.into();
+        self
+    }
+
+    pub fn head_sha<T: Into<String>>(mut self, head_sha: T) -> Self {
+        self.body.head_sha = head_sha.into();
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for CheckRunBuilder {
+    type Response = CheckRun;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_check_run(client, self.owner, self.repo, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags checks
+    /// * patch `/repos/{owner}/{repo}/check-runs/{check_run_id}`
+    /// * docs <https://docs.github.com/rest/reference/checks#update-a-check-run>
+    ///
+    /// Update a check run
+    /// Updates a check run for a specific commit in a repository. Your
+    /// GitHub App must have the `checks:write` permission to edit check runs.
+    UpdateCheckRunBuilder {
+        owner: String,
+        repo: String,
+        check_run_id: i64,
+        body: UpdateCheckRunBody
+    }
+);
+
+builder_string_setters!(UpdateCheckRunBuilder { owner, repo });
+builder_setters!(UpdateCheckRunBuilder { check_run_id: i64 });
+builder_nested_string_setters!(UpdateCheckRunBuilder {
+    body {
+        name,
+        details_url,
+        external_id,
+        started_at,
+        completed_at
+    }
+});
+builder_nested_setters!(UpdateCheckRunBuilder {
+    body {
+        status: CheckRunStatus,
+        conclusion: CheckRunConclusion,
+        output: CheckRunOutputBody
+    }
+});
+check_run_output_setters!(UpdateCheckRunBuilder);
+
+#[async_trait]
+impl Builder for UpdateCheckRunBuilder {
+    type Response = CheckRun;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_check_run(client, self.owner, self.repo, self.check_run_id, &self.body).await
+    }
+}
+
+#[cfg(all(feature = "builders", feature = "client"))]
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        end_points::EndPoints,
+        mock::{Interaction, MockRequester},
+        model::checks::AnnotationLevel,
+    };
+
+    #[tokio::test]
+    async fn test_check_run_builder() {
+        let check_run = json!({
+            "id": 4,
+            "name": "clippy",
+            "node_id": "MDg6Q2hlY2tSdW40",
+            "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+            "url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-runs/4",
+            "status": "completed",
+            "conclusion": "failure",
+            "output": {
+                "title": "Clippy",
+                "summary": "120 warnings",
+                "text": null,
+                "annotations_count": 120,
+                "annotations_url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-runs/4/annotations"
+            },
+            "check_suite": { "id": 5 },
+            "app": null,
+            "pull_requests": []
+        });
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(EndPoints::PostReposownerrepoCheckRuns(
+                    "octocat-rs".to_owned(),
+                    "octocat-rs".to_owned(),
+                ))
+                .respond_with(&check_run),
+            )
+            .with(
+                Interaction::new(EndPoints::PatchReposownerrepoCheckRunscheckRunId(
+                    "octocat-rs".to_owned(),
+                    "octocat-rs".to_owned(),
+                    "4".to_owned(),
+                ))
+                .respond_with(&check_run),
+            );
+
+        let res = CheckRunBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .name("clippy")
+            .head_sha("ce587453ced02b1526dfb4cb910479d431683101")
+            .conclusion(CheckRunConclusion::Failure)
+            .summary("Clippy", "120 warnings")
+            .annotations((1..=120).map(|line| {
+                CheckRunAnnotationBody::new("src/lib.rs", line, line, AnnotationLevel::Warning, "unused variable")
+            }))
+            .image(CheckRunImageBody {
+                alt: "Coverage".to_owned(),
+                image_url: "https://example.com/coverage.png".to_owned(),
+                caption: None,
+            })
+            .action("Fix", "Apply the suggestions", "fix")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(res.conclusion, Some(CheckRunConclusion::Failure));
+
+        let requests = client.requests();
+        let output = |i: usize| requests[i].body.as_ref().unwrap()["output"].clone();
+
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].body.as_ref().unwrap()["actions"][0]["identifier"], "fix");
+        assert_eq!(output(0)["annotations"].as_array().unwrap().len(), 50);
+        assert_eq!(output(0)["images"][0]["alt"], "Coverage");
+        assert_eq!(output(1)["annotations"].as_array().unwrap().len(), 50);
+        assert_eq!(output(2)["annotations"].as_array().unwrap().len(), 20);
+        assert_eq!(output(2)["annotations"][0]["start_line"], 101);
+        assert_eq!(output(2)["summary"], "120 warnings");
+        assert!(output(2).get("images").is_none());
+    }
+
+    fn partial_check_run() -> serde_json::Value {
+        json!({
+            "id": 4,
+            "name": "clippy",
+            "node_id": "MDg6Q2hlY2tSdW40",
+            "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+            "url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-runs/4",
+            "status": "in_progress",
+            "conclusion": null,
+            "output": {
+                "title": "Clippy",
+                "summary": "60 warnings",
+                "text": null,
+                "annotations_count": 50,
+                "annotations_url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-runs/4/annotations"
+            },
+            "check_suite": { "id": 5 },
+            "app": null,
+            "pull_requests": []
+        })
+    }
+
+    #[tokio::test]
+    async fn test_check_run_builder_partial() {
+        let check_run = partial_check_run();
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(EndPoints::PostReposownerrepoCheckRuns(
+                    "octocat-rs".to_owned(),
+                    "octocat-rs".to_owned(),
+                ))
+                .respond_with(&check_run)
+                .status(201),
+            )
+            .with(
+                Interaction::new(EndPoints::PatchReposownerrepoCheckRunscheckRunId(
+                    "octocat-rs".to_owned(),
+                    "octocat-rs".to_owned(),
+                    "4".to_owned(),
+                ))
+                .respond_with(&json!({ "message": "Server Error" }))
+                .status(500),
+            );
+
+        let res = CheckRunBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .name("clippy")
+            .head_sha("ce587453ced02b1526dfb4cb910479d431683101")
+            .summary("Clippy", "60 warnings")
+            .annotations((1..=60).map(|line| {
+                CheckRunAnnotationBody::new("src/lib.rs", line, line, AnnotationLevel::Warning, "unused variable")
+            }))
+            .execute(&client)
+            .await;
+
+        match res {
+            Err(GithubRestError::PartialCheckRun { check_run, error }) => {
+                assert_eq!(check_run.id, 4);
+                assert!(matches!(*error, GithubRestError::ResponseError(500, _)));
+            }
+            res => panic!("expected a partial check run, got {res:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_check_run_builder_partial() {
+        let endpoint = || {
+            EndPoints::PatchReposownerrepoCheckRunscheckRunId(
+                "octocat-rs".to_owned(),
+                "octocat-rs".to_owned(),
+                "4".to_owned(),
+            )
+        };
+
+        let client = MockRequester::new()
+            .with(Interaction::new(endpoint()).respond_with(&partial_check_run()))
+            .with(
+                Interaction::new(endpoint())
+                    .respond_with(&json!({ "message": "Server Error" }))
+                    .status(500),
+            );
+
+        let res = UpdateCheckRunBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .check_run_id(4)
+            .summary("Clippy", "60 warnings")
+            .annotations((1..=60).map(|line| {
+                CheckRunAnnotationBody::new("src/lib.rs", line, line, AnnotationLevel::Warning, "unused variable")
+            }))
+            .execute(&client)
+            .await;
+
+        match res {
+            Err(GithubRestError::PartialCheckRun { check_run, error }) => {
+                assert_eq!(check_run.id, 4);
+                assert!(matches!(*error, GithubRestError::ResponseError(500, _)));
+            }
+            res => panic!("expected a partial check run, got {res:?}"),
+        }
+        assert_eq!(client.requests().len(), 2);
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    builders::{
+        builder, builder_nested_setters, builder_nested_string_setters, builder_nested_string_setters_required,
+        builder_string_setters, Builder,
+    },
+    methods::{
+        create_or_update_file, delete_file, get_contents, CommitAuthorBody, CreateOrUpdateFileBody, DeleteFileBody,
+        GetContentsBody,
+    },
+    model::repositories::contents::{Contents, FileCommit},
+    GithubRestError, Requester,
+};
+use async_trait::async_trait;
+
+builder!(
+    /// * tags repos
+    /// * get `/repos/{owner}/{repo}/contents/{path}`
+    /// * docs <https://docs.github.com/rest/reference/repos#get-repository-content>
+    ///
+    /// Get repository content
+    /// Gets the contents of a file or directory in a repository. Specify the
+    /// file path or directory in `:path`. If you omit `:path`, you will
+    /// receive the contents of the repository's root directory.
+    GetContentsBuilder {
+        owner: String,
+        repo: String,
+        path: String,
+        body: GetContentsBody
+    }
+);
+
+builder_string_setters!(GetContentsBuilder { owner, repo, path });
+builder_nested_string_setters!(GetContentsBuilder { body { reference } });
+
+#[async_trait]
+impl Builder for GetContentsBuilder {
+    type Response = Contents;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        get_contents(client, self.owner, self.repo, self.path, Some(&self.body)).await
+    }
+}
+
+builder!(
+    /// * tags repos
+    /// * put `/repos/{owner}/{repo}/contents/{path}`
+    /// * docs <https://docs.github.com/rest/reference/repos#create-or-update-file-contents>
+    ///
+    /// Create or update file contents
+    /// Creates a new file or replaces an existing file in a repository. The
+    /// `sha` of the file being replaced is required when updating a file.
+    CreateOrUpdateFileBuilder {
+        owner: String,
+        repo: String,
+        path: String,
+        body: CreateOrUpdateFileBody
+    }
+);
+
+builder_string_setters!(CreateOrUpdateFileBuilder { owner, repo, path });
+builder_nested_string_setters!(CreateOrUpdateFileBuilder { body { sha, branch } });
+builder_nested_string_setters_required!(CreateOrUpdateFileBuilder { body { message } });
+builder_nested_setters!(CreateOrUpdateFileBuilder {
+    body {
+        committer: CommitAuthorBody,
+        author: CommitAuthorBody
+    }
+});
+
+impl CreateOrUpdateFileBuilder {
+    /// Sets the new content of the file, which must already be encoded using
+    /// Base64.
+    pub fn encoded_content<T: Into<String>>(mut self, content: T) -> Self {
+        self.body.content = content.into();
+        self
+    }
+
+    /// Sets the new content of the file, encoding it using Base64.
+    #[cfg(feature = "client")]
+    pub fn content<C: AsRef<[u8]>>(mut self, content: C) -> Self {
+        self.body.set_content(content);
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for CreateOrUpdateFileBuilder {
+    type Response = FileCommit;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_or_update_file(client, self.owner, self.repo, self.path, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags repos
+    /// * delete `/repos/{owner}/{repo}/contents/{path}`
+    /// * docs <https://docs.github.com/rest/reference/repos#delete-a-file>
+    ///
+    /// Delete a file
+    /// Deletes a file in a repository.
+    DeleteFileBuilder {
+        owner: String,
+        repo: String,
+        path: String,
+        body: DeleteFileBody
+    }
+);
+
+builder_string_setters!(DeleteFileBuilder { owner, repo, path });
+builder_nested_string_setters!(DeleteFileBuilder { body { branch } });
+builder_nested_string_setters_required!(DeleteFileBuilder { body { message, sha } });
+builder_nested_setters!(DeleteFileBuilder {
+    body {
+        committer: CommitAuthorBody,
+        author: CommitAuthorBody
+    }
+});
+
+#[async_trait]
+impl Builder for DeleteFileBuilder {
+    type Response = FileCommit;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        delete_file(client, self.owner, self.repo, self.path, &self.body).await
+    }
+}
+
+#[cfg(all(feature = "builders", feature = "client"))]
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        builders::{Builder, CreateOrUpdateFileBuilder, GetContentsBuilder},
+        end_points::EndPoints,
+        mock::{Interaction, MockRequester},
+        model::repositories::contents::ContentType,
+    };
+
+    #[tokio::test]
+    async fn test_get_contents() {
+        let endpoint = EndPoints::GetReposownerrepoContentspath(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "github-rest/src".to_owned(),
+        );
+
+        let client = MockRequester::new().with(
+            Interaction::new(endpoint)
+                .query(&[("ref", "main")])
+                .respond_with(&json!([
+                    {
+                        "name": "builders",
+                        "path": "github-rest/src/builders",
+                        "sha": "5c8e5a2a4d4a4ea0ab2e1e0e3e8d0a3f4bb2d7c1",
+                        "size": 0,
+                        "url": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/github-rest/src/builders?ref=main",
+                        "html_url": "https://github.com/octocat-rs/octocat-rs/tree/main/github-rest/src/builders",
+                        "git_url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/trees/5c8e5a2a4d4a4ea0ab2e1e0e3e8d0a3f4bb2d7c1",
+                        "download_url": null,
+                        "type": "dir",
+                        "_links": {
+                            "self": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/github-rest/src/builders?ref=main",
+                            "git": "https://api.github.com/repos/octocat-rs/octocat-rs/git/trees/5c8e5a2a4d4a4ea0ab2e1e0e3e8d0a3f4bb2d7c1",
+                            "html": "https://github.com/octocat-rs/octocat-rs/tree/main/github-rest/src/builders"
+                        }
+                    },
+                    {
+                        "name": "lib.rs",
+                        "path": "github-rest/src/lib.rs",
+                        "sha": "9a0c4b1e6f7d3e2a8b5c4d1e0f9a8b7c6d5e4f3a",
+                        "size": 5123,
+                        "url": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/github-rest/src/lib.rs?ref=main",
+                        "html_url": "https://github.com/octocat-rs/octocat-rs/blob/main/github-rest/src/lib.rs",
+                        "git_url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/blobs/9a0c4b1e6f7d3e2a8b5c4d1e0f9a8b7c6d5e4f3a",
+                        "download_url": "https://raw.githubusercontent.com/octocat-rs/octocat-rs/main/github-rest/src/lib.rs",
+                        "type": "file",
+                        "_links": {
+                            "self": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/github-rest/src/lib.rs?ref=main",
+                            "git": "https://api.github.com/repos/octocat-rs/octocat-rs/git/blobs/9a0c4b1e6f7d3e2a8b5c4d1e0f9a8b7c6d5e4f3a",
+                            "html": "https://github.com/octocat-rs/octocat-rs/blob/main/github-rest/src/lib.rs"
+                        }
+                    }
+                ])),
+        );
+
+        let contents = GetContentsBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .path("github-rest/src")
+            .reference("main")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let entries = contents.directory().unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].type_field, ContentType::Dir);
+        assert_eq!(entries[1].path, "github-rest/src/lib.rs");
+        assert!(entries[1].content.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_or_update_file() {
+        let endpoint = EndPoints::PutReposownerrepoContentspath(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "CHANGELOG.md".to_owned(),
+        );
+
+        let client = MockRequester::new().with(Interaction::new(endpoint).respond_with(&json!({
+            "content": {
+                "name": "CHANGELOG.md",
+                "path": "CHANGELOG.md",
+                "sha": "95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+                "size": 12,
+                "url": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/CHANGELOG.md",
+                "html_url": "https://github.com/octocat-rs/octocat-rs/blob/main/CHANGELOG.md",
+                "git_url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/blobs/95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+                "download_url": "https://raw.githubusercontent.com/octocat-rs/octocat-rs/main/CHANGELOG.md",
+                "type": "file",
+                "_links": {
+                    "self": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/CHANGELOG.md",
+                    "git": "https://api.github.com/repos/octocat-rs/octocat-rs/git/blobs/95b966ae1c166bd92f8ae7d1c313e738c731dfc3",
+                    "html": "https://github.com/octocat-rs/octocat-rs/blob/main/CHANGELOG.md"
+                }
+            },
+            "commit": {
+                "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+                "node_id": "MDY6Q29tbWl0NzYzODQxN2RiNmQ1OWYzYzQzMWQzZTFmMjYxY2M2MzcxNTU2ODRjZA==",
+                "url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/commits/7638417db6d59f3c431d3e1f261cc637155684cd",
+                "html_url": "https://github.com/octocat-rs/octocat-rs/commit/7638417db6d59f3c431d3e1f261cc637155684cd",
+                "author": {
+                    "date": "2023-06-01T00:00:00Z",
+                    "name": "Monalisa Octocat",
+                    "email": "octocat@github.com"
+                },
+                "committer": {
+                    "date": "2023-06-01T00:00:00Z",
+                    "name": "Monalisa Octocat",
+                    "email": "octocat@github.com"
+                },
+                "message": "Update changelog",
+                "tree": {
+                    "url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/trees/691272480426f78a0138979dd3ce63b77f706feb",
+                    "sha": "691272480426f78a0138979dd3ce63b77f706feb"
+                },
+                "parents": [
+                    {
+                        "url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/commits/1acc419d4d6a9ce985db7be48c6349a0475975b5",
+                        "html_url": "https://github.com/octocat-rs/octocat-rs/commit/1acc419d4d6a9ce985db7be48c6349a0475975b5",
+                        "sha": "1acc419d4d6a9ce985db7be48c6349a0475975b5"
+                    }
+                ],
+                "verification": {
+                    "verified": false,
+                    "reason": "unsigned",
+                    "signature": null,
+                    "payload": null
+                }
+            }
+        })));
+
+        let res = CreateOrUpdateFileBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .path("CHANGELOG.md")
+            .message("Update changelog")
+            .content("# Changelog\n")
+            .sha("3d21ec53")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let content = res.content.unwrap();
+
+        assert_eq!(content.path, "CHANGELOG.md");
+        assert_eq!(content.sha, "95b966ae1c166bd92f8ae7d1c313e738c731dfc3");
+        assert_eq!(res.commit.message, "Update changelog");
+        assert_eq!(res.commit.parents.len(), 1);
+        assert_eq!(
+            client.requests()[0].body,
+            Some(json!({
+                "message": "Update changelog",
+                "content": "IyBDaGFuZ2Vsb2cK",
+                "sha": "3d21ec53"
+            }))
+        );
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
     builders::{
         builder, builder_nested_setters, builder_nested_string_setters, builder_nested_string_setters_required,
-        builder_string_setters, Builder,
+        builder_setters, builder_string_setters, Builder,
+    },
+    methods::{
+        add_assignees, create_issue, create_issue_comment, get_issue_comments, get_issues, lock_issue,
+        remove_assignees, update_issue, update_issue_comment, AssigneesBody, CreateIssueBody, GetIssueCommentsBody,
+        GetIssuesBody, IssueCommentBody, IssueState, LockIssueBody, LockReason, StateReason, UpdateIssueBody,
+    },
+    model::issues::{
+        comments::{IssueComment, IssueComments},
+        nested, Issue, Issues,
     },
-    methods::{create_issue, get_issues, CreateIssueBody, GetIssuesBody, IssueState},
-    model::issues::{Issue, Issues},
     GithubRestError, Requester,
 };
 use async_trait::async_trait;
@@ -89,6 +96,252 @@ impl Builder for CreateIssueBuilder {
     }
 }
 
+builder!(
+    /// * tags issues
+    /// * patch `/repos/{owner}/{repo}/issues/{issue_number}`
+    /// * docs <https://docs.github.com/rest/reference/issues/#update-an-issue>
+    ///
+    /// Update an issue
+    /// Issue owners and users with push access can edit an issue.
+    UpdateIssueBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: UpdateIssueBody
+    }
+);
+
+builder_string_setters!(UpdateIssueBuilder { owner, repo });
+builder_setters!(UpdateIssueBuilder { issue_number: i64 });
+builder_nested_string_setters!(UpdateIssueBuilder { body { title, body } });
+builder_nested_setters!(UpdateIssueBuilder {
+    body {
+        state: nested::IssueState,
+        state_reason: StateReason,
+        labels: Vec<String>,
+        assignees: Vec<String>
+    }
+});
+
+impl UpdateIssueBuilder {
+    /// Sets the milestone of the issue, or removes it if `None` is passed.
+    pub fn milestone(mut self, milestone: Option<i64>) -> Self {
+        self.body.milestone = Some(milestone);
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for UpdateIssueBuilder {
+    type Response = Issue;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_issue(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * put `/repos/{owner}/{repo}/issues/{issue_number}/lock`
+    /// * docs <https://docs.github.com/rest/reference/issues#lock-an-issue>
+    ///
+    /// Lock an issue
+    /// Users with push access can lock an issue or pull request's conversation.
+    LockIssueBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: LockIssueBody
+    }
+);
+
+builder_string_setters!(LockIssueBuilder { owner, repo });
+builder_setters!(LockIssueBuilder { issue_number: i64 });
+builder_nested_setters!(LockIssueBuilder { body { lock_reason: LockReason } });
+
+#[async_trait]
+impl Builder for LockIssueBuilder {
+    type Response = ();
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        lock_issue(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * post `/repos/{owner}/{repo}/issues/{issue_number}/assignees`
+    /// * docs <https://docs.github.com/rest/reference/issues#add-assignees-to-an-issue>
+    ///
+    /// Add assignees to an issue
+    /// Adds up to 10 assignees to an issue. Users already assigned to an
+    /// issue are not replaced.
+    AddAssigneesBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: AssigneesBody
+    }
+);
+
+builder!(
+    /// * tags issues
+    /// * delete `/repos/{owner}/{repo}/issues/{issue_number}/assignees`
+    /// * docs <https://docs.github.com/rest/reference/issues#remove-assignees-from-an-issue>
+    ///
+    /// Remove assignees from an issue
+    /// Removes one or more assignees from an issue.
+    RemoveAssigneesBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: AssigneesBody
+    }
+);
+
+builder_string_setters!(AddAssigneesBuilder { owner, repo });
+builder_setters!(AddAssigneesBuilder { issue_number: i64 });
+builder_string_setters!(RemoveAssigneesBuilder { owner, repo });
+builder_setters!(RemoveAssigneesBuilder { issue_number: i64 });
+
+impl AddAssigneesBuilder {
+    /// Adds a user to the list of assignees.
+    pub fn assignee<T: Into<String>>(mut self, assignee: T) -> Self {
+        self.body.assignees.push(assignee.into());
+        self
+    }
+}
+
+impl RemoveAssigneesBuilder {
+    /// Adds a user to the list of assignees to remove.
+    pub fn assignee<T: Into<String>>(mut self, assignee: T) -> Self {
+        self.body.assignees.push(assignee.into());
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for AddAssigneesBuilder {
+    type Response = Issue;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        add_assignees(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+#[async_trait]
+impl Builder for RemoveAssigneesBuilder {
+    type Response = Issue;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        remove_assignees(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * get `/repos/{owner}/{repo}/issues/{issue_number}/comments`
+    /// * docs <https://docs.github.com/rest/reference/issues#list-issue-comments>
+    ///
+    /// List issue comments
+    /// Issue Comments are ordered by ascending ID.
+    GetIssueCommentsBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: GetIssueCommentsBody
+    }
+);
+
+builder_string_setters!(GetIssueCommentsBuilder { owner, repo });
+builder_setters!(GetIssueCommentsBuilder { issue_number: i64 });
+builder_nested_string_setters!(GetIssueCommentsBuilder { body { since, per_page, page } });
+
+#[async_trait]
+impl Builder for GetIssueCommentsBuilder {
+    type Response = IssueComments;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        get_issue_comments(client, self.owner, self.repo, self.issue_number, Some(&self.body)).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * post `/repos/{owner}/{repo}/issues/{issue_number}/comments`
+    /// * docs <https://docs.github.com/rest/reference/issues#create-an-issue-comment>
+    ///
+    /// Create an issue comment
+    /// This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting.
+    CreateIssueCommentBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: IssueCommentBody
+    }
+);
+
+builder_string_setters!(CreateIssueCommentBuilder { owner, repo });
+builder_setters!(CreateIssueCommentBuilder { issue_number: i64 });
+builder_nested_string_setters_required!(CreateIssueCommentBuilder { body { body } });
+
+#[async_trait]
+impl Builder for CreateIssueCommentBuilder {
+    type Response = IssueComment;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_issue_comment(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * patch `/repos/{owner}/{repo}/issues/comments/{comment_id}`
+    /// * docs <https://docs.github.com/rest/reference/issues#update-an-issue-comment>
+    ///
+    /// Update an issue comment
+    UpdateIssueCommentBuilder {
+        owner: String,
+        repo: String,
+        comment_id: i64,
+        body: IssueCommentBody
+    }
+);
+
+builder_string_setters!(UpdateIssueCommentBuilder { owner, repo });
+builder_setters!(UpdateIssueCommentBuilder { comment_id: i64 });
+builder_nested_string_setters_required!(UpdateIssueCommentBuilder { body { body } });
+
+#[async_trait]
+impl Builder for UpdateIssueCommentBuilder {
+    type Response = IssueComment;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_issue_comment(client, self.owner, self.repo, self.comment_id, &self.body).await
+    }
+}
+
 #[cfg(all(feature = "builders", feature = "client"))]
 #[cfg(test)]
 mod tests {
@@ -124,4 +377,51 @@ mod tests {
 
         dbg!(res);
     }
+
+    #[tokio::test]
+    async fn test_update_issue_builder() {
+        use serde_json::{json, Value};
+
+        use crate::{
+            end_points::EndPoints,
+            mock::{Interaction, MockRequester},
+        };
+
+        let endpoint = EndPoints::PatchReposownerrepoIssuesissueNumber(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "42".to_owned(),
+        );
+
+        let fixture: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/webhooks/issue_comment.json")).unwrap();
+        let mut issue = fixture["issue"].clone();
+        issue["number"] = json!(42);
+        issue["state"] = json!("closed");
+        issue["state_reason"] = json!("not_planned");
+        issue["milestone"] = Value::Null;
+        issue["closed_at"] = json!("2019-05-15T15:21:00Z");
+
+        let client = MockRequester::new().with(Interaction::new(endpoint).respond_with(&issue));
+
+        let res = UpdateIssueBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .issue_number(42)
+            .state(nested::IssueState::Closed)
+            .state_reason(StateReason::NotPlanned)
+            .milestone(None)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(res.number, 42);
+        assert_eq!(res.state, nested::IssueState::Closed);
+        assert!(res.milestone.is_none());
+        assert_eq!(res.closed_at.as_deref(), Some("2019-05-15T15:21:00Z"));
+        assert_eq!(
+            client.requests()[0].body,
+            Some(json!({ "state": "closed", "state_reason": "not_planned", "milestone": null }))
+        );
+    }
 }
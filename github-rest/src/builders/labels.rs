@@ -0,0 +1,196 @@
+use crate::{
+    builders::{builder, builder_nested_string_setters, builder_setters, builder_string_setters, Builder},
+    methods::{add_labels, create_label, set_labels, update_label, CreateLabelBody, LabelsBody, UpdateLabelBody},
+    model::issues::Label,
+    GithubRestError, Requester,
+};
+use async_trait::async_trait;
+
+builder!(
+    /// * tags issues
+    /// * post `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+    /// * docs <https://docs.github.com/rest/reference/issues#add-labels-to-an-issue>
+    ///
+    /// Add labels to an issue
+    /// Returns every label of the issue.
+    AddLabelsBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: LabelsBody
+    }
+);
+
+builder!(
+    /// * tags issues
+    /// * put `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+    /// * docs <https://docs.github.com/rest/reference/issues#set-labels-for-an-issue>
+    ///
+    /// Set labels for an issue
+    /// Removes any previous labels and sets the new labels for an issue.
+    SetLabelsBuilder {
+        owner: String,
+        repo: String,
+        issue_number: i64,
+        body: LabelsBody
+    }
+);
+
+builder_string_setters!(AddLabelsBuilder { owner, repo });
+builder_setters!(AddLabelsBuilder { issue_number: i64 });
+builder_string_setters!(SetLabelsBuilder { owner, repo });
+builder_setters!(SetLabelsBuilder { issue_number: i64 });
+
+impl AddLabelsBuilder {
+    /// Adds a label to the list of labels to add.
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.body.labels.push(label.into());
+        self
+    }
+}
+
+impl SetLabelsBuilder {
+    /// Adds a label to the new list of labels.
+    pub fn label<T: Into<String>>(mut self, label: T) -> Self {
+        self.body.labels.push(label.into());
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for AddLabelsBuilder {
+    type Response = Vec<Label>;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        add_labels(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+#[async_trait]
+impl Builder for SetLabelsBuilder {
+    type Response = Vec<Label>;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        set_labels(client, self.owner, self.repo, self.issue_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * post `/repos/{owner}/{repo}/labels`
+    /// * docs <https://docs.github.com/rest/reference/issues#create-a-label>
+    ///
+    /// Create a label
+    /// Creates a label for the specified repository with the given name and
+    /// color.
+    CreateLabelBuilder {
+        owner: String,
+        repo: String,
+        body: CreateLabelBody
+    }
+);
+
+builder_string_setters!(CreateLabelBuilder { owner, repo });
+builder_nested_string_setters!(CreateLabelBuilder { body { color, description } });
+
+impl CreateLabelBuilder {
+    pub fn name<T: Into<String>>(mut self, name: T) -> Self {
+        self.body.name = name.into();
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for CreateLabelBuilder {
+    type Response = Label;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_label(client, self.owner, self.repo, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * patch `/repos/{owner}/{repo}/labels/{name}`
+    /// * docs <https://docs.github.com/rest/reference/issues#update-a-label>
+    ///
+    /// Update a label
+    /// Updates a label using the given label name.
+    UpdateLabelBuilder {
+        owner: String,
+        repo: String,
+        name: String,
+        body: UpdateLabelBody
+    }
+);
+
+builder_string_setters!(UpdateLabelBuilder { owner, repo, name });
+builder_nested_string_setters!(UpdateLabelBuilder { body { new_name, color, description } });
+
+#[async_trait]
+impl Builder for UpdateLabelBuilder {
+    type Response = Label;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_label(client, self.owner, self.repo, self.name, &self.body).await
+    }
+}
+
+#[cfg(all(feature = "builders", feature = "client"))]
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        end_points::EndPoints,
+        mock::{Interaction, MockRequester},
+    };
+
+    #[tokio::test]
+    async fn test_add_labels_builder() {
+        let endpoint = EndPoints::PostReposownerrepoIssuesissueNumberLabels(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "42".to_owned(),
+        );
+
+        let client = MockRequester::new().with(
+            Interaction::new(endpoint)
+                .body(&json!({ "labels": ["bug", "triage"] }))
+                .respond_with(&json!([{
+                    "id": 208045946,
+                    "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+                    "url": "https://api.github.com/repos/octocat-rs/octocat-rs/labels/bug",
+                    "name": "bug",
+                    "description": "Something isn't working",
+                    "color": "f29513",
+                    "default": true
+                }])),
+        );
+
+        let labels = AddLabelsBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .issue_number(42)
+            .label("bug")
+            .label("triage")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(labels[0].name, "bug");
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    builders::{
+        builder, builder_nested_setters, builder_nested_string_setters, builder_setters, builder_string_setters,
+        Builder,
+    },
+    methods::{create_milestone, get_milestones, update_milestone, GetMilestonesBody, MilestoneBody},
+    model::issues::milestones::{Milestone, MilestoneState},
+    GithubRestError, Requester,
+};
+use async_trait::async_trait;
+
+builder!(
+    /// * tags issues
+    /// * get `/repos/{owner}/{repo}/milestones`
+    /// * docs <https://docs.github.com/rest/reference/issues#list-milestones>
+    ///
+    /// List milestones
+    /// Lists milestones for a repository.
+    GetMilestonesBuilder {
+        owner: String,
+        repo: String,
+        body: GetMilestonesBody
+    }
+);
+
+builder_string_setters!(GetMilestonesBuilder { owner, repo });
+builder_nested_string_setters!(GetMilestonesBuilder {
+    body {
+        state,
+        sort,
+        direction,
+        per_page,
+        page
+    }
+});
+
+#[async_trait]
+impl Builder for GetMilestonesBuilder {
+    type Response = Vec<Milestone>;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        get_milestones(client, self.owner, self.repo, Some(&self.body)).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * post `/repos/{owner}/{repo}/milestones`
+    /// * docs <https://docs.github.com/rest/reference/issues#create-a-milestone>
+    ///
+    /// Create a milestone
+    /// Creates a milestone.
+    CreateMilestoneBuilder {
+        owner: String,
+        repo: String,
+        body: MilestoneBody
+    }
+);
+
+builder_string_setters!(CreateMilestoneBuilder { owner, repo });
+builder_nested_string_setters!(CreateMilestoneBuilder { body { title, description, due_on } });
+builder_nested_setters!(CreateMilestoneBuilder { body { state: MilestoneState } });
+
+#[async_trait]
+impl Builder for CreateMilestoneBuilder {
+    type Response = Milestone;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_milestone(client, self.owner, self.repo, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags issues
+    /// * patch `/repos/{owner}/{repo}/milestones/{milestone_number}`
+    /// * docs <https://docs.github.com/rest/reference/issues#update-a-milestone>
+    ///
+    /// Update a milestone
+    UpdateMilestoneBuilder {
+        owner: String,
+        repo: String,
+        milestone_number: i64,
+        body: MilestoneBody
+    }
+);
+
+builder_string_setters!(UpdateMilestoneBuilder { owner, repo });
+builder_setters!(UpdateMilestoneBuilder { milestone_number: i64 });
+builder_nested_string_setters!(UpdateMilestoneBuilder { body { title, description, due_on } });
+builder_nested_setters!(UpdateMilestoneBuilder { body { state: MilestoneState } });
+
+#[async_trait]
+impl Builder for UpdateMilestoneBuilder {
+    type Response = Milestone;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_milestone(client, self.owner, self.repo, self.milestone_number, &self.body).await
+    }
+}
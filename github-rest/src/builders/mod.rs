@@ -2,17 +2,27 @@
 //! information
 
 use crate::{GithubRestError, Requester};
+pub use actions::*;
 use async_trait::async_trait;
+pub use checks::*;
 pub use commits::*;
+pub use contents::*;
 pub use gists::*;
 pub use issues::*;
+pub use labels::*;
+pub use milestones::*;
 pub use pull_requests::*;
 pub use reactions::*;
 use serde::de::DeserializeOwned;
 
+mod actions;
+mod checks;
 mod commits;
+mod contents;
 mod gists;
 mod issues;
+mod labels;
+mod milestones;
 mod pull_requests;
 mod reactions;
 
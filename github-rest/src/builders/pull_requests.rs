@@ -1,7 +1,16 @@
 use crate::{
-    builders::{builder, builder_nested_setters, builder_nested_string_setters, builder_string_setters, Builder},
-    methods::{get_pulls, GetPullsBody},
-    model::pull_requests::{PullRequestState, Pulls},
+    builders::{
+        builder, builder_nested_setters, builder_nested_string_setters, builder_nested_string_setters_required,
+        builder_setters, builder_string_setters, Builder,
+    },
+    methods::{
+        create_pull, create_review, get_pulls, merge_pull, request_reviewers, update_pull, CreatePullBody,
+        CreateReviewBody, GetPullsBody, MergeMethod, MergePullBody, RequestReviewersBody, ReviewCommentBody,
+        ReviewEvent, UpdatePullBody,
+    },
+    model::pull_requests::{
+        reviews::PullRequestReview, PullRequest, PullRequestMergeResult, PullRequestState, Pulls, SimplePullRequest,
+    },
     GithubRestError, Requester,
 };
 use async_trait::async_trait;
@@ -36,10 +45,217 @@ impl Builder for GetPullsBuilder {
     }
 }
 
+builder!(
+    /// * tags pulls
+    /// * post `/repos/{owner}/{repo}/pulls`
+    /// * docs <https://docs.github.com/rest/reference/pulls#create-a-pull-request>
+    ///
+    /// Create a pull request
+    /// To open or update a pull request in a public repository, you must have write access to the head or the source branch. For organization-owned repositories, you must be a member of the organization that owns the repository to open or update a pull request.
+    CreatePullBuilder {
+        owner: String,
+        repo: String,
+        body: CreatePullBody
+    }
+);
+
+builder_string_setters!(CreatePullBuilder { owner, repo });
+builder_nested_string_setters!(CreatePullBuilder { body { title, body } });
+builder_nested_string_setters_required!(CreatePullBuilder { body { head, base } });
+builder_nested_setters!(CreatePullBuilder {
+    body {
+        maintainer_can_modify: bool,
+        draft: bool,
+        issue: i64
+    }
+});
+
+#[async_trait]
+impl Builder for CreatePullBuilder {
+    type Response = PullRequest;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_pull(client, self.owner, self.repo, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags pulls
+    /// * patch `/repos/{owner}/{repo}/pulls/{pull_number}`
+    /// * docs <https://docs.github.com/rest/reference/pulls/#update-a-pull-request>
+    ///
+    /// Update a pull request
+    /// To open or update a pull request in a public repository, you must have write access to the head or the source branch. For organization-owned repositories, you must be a member of the organization that owns the repository to open or update a pull request.
+    UpdatePullBuilder {
+        owner: String,
+        repo: String,
+        pull_number: i64,
+        body: UpdatePullBody
+    }
+);
+
+builder_string_setters!(UpdatePullBuilder { owner, repo });
+builder_setters!(UpdatePullBuilder { pull_number: i64 });
+builder_nested_string_setters!(UpdatePullBuilder { body { title, body, base } });
+builder_nested_setters!(UpdatePullBuilder {
+    body {
+        state: PullRequestState,
+        maintainer_can_modify: bool
+    }
+});
+
+#[async_trait]
+impl Builder for UpdatePullBuilder {
+    type Response = PullRequest;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_pull(client, self.owner, self.repo, self.pull_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags pulls
+    /// * put `/repos/{owner}/{repo}/pulls/{pull_number}/merge`
+    /// * docs <https://docs.github.com/rest/reference/pulls#merge-a-pull-request>
+    ///
+    /// Merge a pull request
+    /// If `sha` is set, the pull request is only merged if its head still
+    /// matches it. Otherwise, the request fails with
+    /// [`GithubRestError::Conflict`].
+    MergePullBuilder {
+        owner: String,
+        repo: String,
+        pull_number: i64,
+        body: MergePullBody
+    }
+);
+
+builder_string_setters!(MergePullBuilder { owner, repo });
+builder_setters!(MergePullBuilder { pull_number: i64 });
+builder_nested_string_setters!(MergePullBuilder { body { commit_title, commit_message, sha } });
+builder_nested_setters!(MergePullBuilder { body { merge_method: MergeMethod } });
+
+#[async_trait]
+impl Builder for MergePullBuilder {
+    type Response = PullRequestMergeResult;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        merge_pull(client, self.owner, self.repo, self.pull_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags pulls
+    /// * post `/repos/{owner}/{repo}/pulls/{pull_number}/reviews`
+    /// * docs <https://docs.github.com/rest/reference/pulls#create-a-review-for-a-pull-request>
+    ///
+    /// Create a review for a pull request
+    /// Pull request reviews created in the `PENDING` state do not include the
+    /// `submitted_at` property in the response.
+    CreateReviewBuilder {
+        owner: String,
+        repo: String,
+        pull_number: i64,
+        body: CreateReviewBody
+    }
+);
+
+builder_string_setters!(CreateReviewBuilder { owner, repo });
+builder_setters!(CreateReviewBuilder { pull_number: i64 });
+builder_nested_string_setters!(CreateReviewBuilder { body { commit_id, body } });
+builder_nested_setters!(CreateReviewBuilder {
+    body {
+        event: ReviewEvent,
+        comments: Vec<ReviewCommentBody>
+    }
+});
+
+impl CreateReviewBuilder {
+    /// Adds an inline comment on a single line of the diff. `line` refers to
+    /// the new version of the file.
+    pub fn comment<P, B>(mut self, path: P, line: i64, body: B) -> Self
+    where
+        P: Into<String>,
+        B: Into<String>,
+    {
+        self.body.comments.get_or_insert_with(Vec::new).push(ReviewCommentBody {
+            path: path.into(),
+            body: body.into(),
+            line: Some(line),
+            side: Some("RIGHT".to_owned()),
+            ..Default::default()
+        });
+        self
+    }
+}
+
+#[async_trait]
+impl Builder for CreateReviewBuilder {
+    type Response = PullRequestReview;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        create_review(client, self.owner, self.repo, self.pull_number, &self.body).await
+    }
+}
+
+builder!(
+    /// * tags pulls
+    /// * post `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers`
+    /// * docs <https://docs.github.com/rest/reference/pulls#request-reviewers-for-a-pull-request>
+    ///
+    /// Request reviewers for a pull request
+    RequestReviewersBuilder {
+        owner: String,
+        repo: String,
+        pull_number: i64,
+        body: RequestReviewersBody
+    }
+);
+
+builder_string_setters!(RequestReviewersBuilder { owner, repo });
+builder_setters!(RequestReviewersBuilder { pull_number: i64 });
+builder_nested_setters!(RequestReviewersBuilder {
+    body {
+        reviewers: Vec<String>,
+        team_reviewers: Vec<String>
+    }
+});
+
+#[async_trait]
+impl Builder for RequestReviewersBuilder {
+    type Response = SimplePullRequest;
+
+    async fn execute<T>(self, client: &T) -> Result<Self::Response, GithubRestError>
+    where
+        T: Requester,
+    {
+        request_reviewers(client, self.owner, self.repo, self.pull_number, &self.body).await
+    }
+}
+
 #[cfg(all(feature = "builders", feature = "client"))]
 #[cfg(test)]
 mod tests {
-    use crate::client::DefaultRequester;
+    use serde_json::json;
+
+    use crate::{
+        client::DefaultRequester,
+        end_points::EndPoints,
+        mock::{Interaction, MockRequester},
+        model::pull_requests::reviews::ReviewState,
+    };
 
     use super::*;
 
@@ -57,4 +273,113 @@ mod tests {
 
         dbg!(r);
     }
+
+    #[tokio::test]
+    async fn test_merge_pull_builder() {
+        let endpoint = || {
+            EndPoints::PutReposownerrepoPullspullNumberMerge(
+                "octocat-rs".to_owned(),
+                "octocat-rs".to_owned(),
+                "1".to_owned(),
+            )
+        };
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(endpoint())
+                    .body(&json!({ "sha": "6dcb09b5", "merge_method": "squash" }))
+                    .respond_with(
+                        &json!({ "sha": "7638417d", "merged": true, "message": "Pull Request successfully merged" }),
+                    ),
+            )
+            .with(
+                Interaction::new(endpoint())
+                    .body(&json!({ "sha": "e5bd3914", "merge_method": "squash" }))
+                    .respond_with(&json!({ "message": "Head branch was modified. Review and try the merge again." }))
+                    .status(409),
+            );
+
+        let merge = |sha: &str| {
+            MergePullBuilder::new()
+                .owner("octocat-rs")
+                .repo("octocat-rs")
+                .pull_number(1)
+                .sha(sha)
+                .merge_method(MergeMethod::Squash)
+        };
+
+        assert!(merge("6dcb09b5").execute(&client).await.unwrap().merged);
+        assert!(matches!(
+            merge("e5bd3914").execute(&client).await,
+            Err(GithubRestError::Conflict(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_review_builder() {
+        let endpoint = EndPoints::PostReposownerrepoPullspullNumberReviews(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "1".to_owned(),
+        );
+        let client = MockRequester::new().with(Interaction::new(endpoint).respond_with(&json!({
+            "id": 80,
+            "node_id": "MDE3OlB1bGxSZXF1ZXN0UmV2aWV3ODA=",
+            "user": {
+                "login": "octocat",
+                "id": 1,
+                "node_id": "MDQ6VXNlcjE=",
+                "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                "gravatar_id": "",
+                "url": "https://api.github.com/users/octocat",
+                "html_url": "https://github.com/octocat",
+                "followers_url": "https://api.github.com/users/octocat/followers",
+                "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                "organizations_url": "https://api.github.com/users/octocat/orgs",
+                "repos_url": "https://api.github.com/users/octocat/repos",
+                "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                "received_events_url": "https://api.github.com/users/octocat/received_events",
+                "type": "User",
+                "site_admin": false
+            },
+            "body": "A few nits",
+            "state": "CHANGES_REQUESTED",
+            "html_url": "https://github.com/octocat-rs/octocat-rs/pull/1#pullrequestreview-80",
+            "pull_request_url": "https://api.github.com/repos/octocat-rs/octocat-rs/pulls/1",
+            "_links": {
+                "html": { "href": "https://github.com/octocat-rs/octocat-rs/pull/1#pullrequestreview-80" },
+                "pull_request": { "href": "https://api.github.com/repos/octocat-rs/octocat-rs/pulls/1" }
+            },
+            "submitted_at": "2023-06-01T00:00:00Z",
+            "commit_id": "ecdd80bb57125d7ba9641ffaa4d7d2c19d3f3091",
+            "author_association": "COLLABORATOR"
+        })));
+
+        let review = CreateReviewBuilder::new()
+            .owner("octocat-rs")
+            .repo("octocat-rs")
+            .pull_number(1)
+            .event(ReviewEvent::RequestChanges)
+            .body("A few nits")
+            .comment("src/lib.rs", 12, "Typo")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(review.id, 80);
+        assert_eq!(review.state, ReviewState::ChangesRequested);
+        assert_eq!(review.body, "A few nits");
+        assert_eq!(review.user.unwrap().login, "octocat");
+        assert_eq!(
+            client.requests()[0].body,
+            Some(json!({
+                "body": "A few nits",
+                "event": "REQUEST_CHANGES",
+                "comments": [{ "path": "src/lib.rs", "body": "Typo", "line": 12, "side": "RIGHT" }]
+            }))
+        );
+    }
 }
@@ -0,0 +1,379 @@
+//! Contains [`CachedRequester`], which uses conditional requests to avoid
+//! spending rate limit on resources that haven't changed.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use github_api_octocat::end_points::{EndPoints, Methods};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{GithubRestError, Requester, Response};
+
+/// A cached response body along with the validators needed to revalidate it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: String,
+}
+
+/// A storage backend for [`CachedRequester`].
+///
+/// Keys are made up of the path of an [`EndPoints`] variant and the query
+/// string, e.g. `/repos/octocat-rs/octocat-rs/commits?per_page=10`.
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+
+    fn insert(&self, key: &str, entry: CacheEntry);
+}
+
+/// A [`CacheStore`] that keeps entries in memory.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(key.to_owned(), entry);
+    }
+}
+
+/// A [`CacheStore`] that keeps every entry in a JSON file inside of a
+/// directory, so that it survives restarts. Errors while reading or writing
+/// entries are ignored, causing a normal request to be sent instead.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    #[serde(flatten)]
+    entry: CacheEntry,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl DiskCache {
+    /// Creates a [`DiskCache`], creating `dir` if it doesn't exist yet.
+    pub fn new<P: Into<std::path::PathBuf>>(dir: P) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        // FNV-1a, as the file names need to stay the same across builds
+        let hash = key.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+
+        self.dir.join(format!("{hash:016x}.json"))
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let file = std::fs::read_to_string(self.path(key)).ok()?;
+        let stored = serde_json::from_str::<DiskEntry>(&file).ok()?;
+
+        (stored.key == key).then_some(stored.entry)
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        let stored = DiskEntry {
+            key: key.to_owned(),
+            entry,
+        };
+
+        if let Ok(json) = serde_json::to_string(&stored) {
+            let _ = std::fs::write(self.path(key), json);
+        }
+    }
+}
+
+/// Wraps a [`Requester`], caching the responses to `GET` requests that come
+/// with an `ETag` or `Last-Modified` header.
+///
+/// Cached resources are revalidated using `If-None-Match` and
+/// `If-Modified-Since`. When GitHub answers with `304 Not Modified`, which
+/// doesn't count against the rate limit, the cached body is returned with a
+/// `200` status code and the headers of the `304` response.
+///
+/// ```rust,ignore
+/// let client = CachedRequester::new(DefaultRequester::new_none(), MemoryCache::new());
+///
+/// // Only the first call uses up the rate limit, as long as nothing changed.
+/// for _ in 0..10 {
+///     get_commits(&client, "octocat-rs", "octocat-rs", None).await?;
+/// }
+/// ```
+pub struct CachedRequester<R, S = MemoryCache> {
+    inner: R,
+    store: S,
+}
+
+impl<R, S> CachedRequester<R, S>
+where
+    R: Requester,
+    S: CacheStore,
+{
+    pub fn new(inner: R, store: S) -> Self {
+        Self { inner, store }
+    }
+
+    /// Gets the wrapped [`Requester`].
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets the [`CacheStore`] used by the current requester.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<R, S> Requester for CachedRequester<R, S>
+where
+    R: Requester,
+    S: CacheStore,
+{
+    type Body = R::Body;
+
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        Ok(self.raw_req_with_meta(url, query, body).await?.body)
+    }
+
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        self.raw_req_with_headers(url, query, body, &[]).await
+    }
+
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        if !matches!(url.method(), Methods::Get) {
+            return self.inner.raw_req_with_headers(url, query, body, headers).await;
+        }
+
+        let key = match query {
+            Some(query) => format!("{}?{}", url.path(), serde_urlencoded::to_string(query)?),
+            None => url.path(),
+        };
+        let entry = self.store.get(&key);
+
+        let mut headers = headers.to_vec();
+
+        if let Some(entry) = &entry {
+            if let Some(etag) = &entry.etag {
+                headers.push(("If-None-Match", etag));
+            }
+
+            if let Some(last_modified) = &entry.last_modified {
+                headers.push(("If-Modified-Since", last_modified));
+            }
+        }
+
+        let res = self.inner.raw_req_with_headers(url, query, body, &headers).await?;
+
+        match entry {
+            Some(entry) if res.is_not_modified() => Ok(Response {
+                body: entry.body,
+                status: 200,
+                headers: res.headers,
+            }),
+            _ => {
+                if res.is_success() && (res.etag().is_some() || res.last_modified().is_some()) {
+                    let entry = CacheEntry {
+                        etag: res.etag().map(ToOwned::to_owned),
+                        last_modified: res.last_modified().map(ToOwned::to_owned),
+                        body: res.body.clone(),
+                    };
+
+                    self.store.insert(&key, entry);
+                }
+
+                Ok(res)
+            }
+        }
+    }
+
+    /// Binary responses are not cached.
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+    {
+        self.inner.raw_req_bytes(url, query).await
+    }
+
+    /// Requests to arbitrary paths are not cached.
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        self.inner.raw_req_path(method, path, query, body).await
+    }
+
+    async fn req<T, V, A: DeserializeOwned>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<A, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let r = self.raw_req(url, query, body).await?;
+        Ok(serde_json::from_str(&r)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers with `304 Not Modified` if the right `If-None-Match` header is
+    /// sent, and records the headers of every request.
+    #[derive(Default)]
+    struct FakeRequester {
+        requests: Mutex<Vec<Vec<(String, String)>>>,
+    }
+
+    #[async_trait]
+    impl Requester for FakeRequester {
+        type Body = String;
+
+        async fn raw_req<T, V>(
+            &self,
+            url: EndPoints,
+            query: Option<&T>,
+            body: Option<V>,
+        ) -> Result<String, GithubRestError>
+        where
+            T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+            V: Into<Self::Body> + std::marker::Send,
+        {
+            Ok(self.raw_req_with_headers(url, query, body, &[]).await?.body)
+        }
+
+        async fn raw_req_with_headers<T, V>(
+            &self,
+            _url: EndPoints,
+            _query: Option<&T>,
+            _body: Option<V>,
+            headers: &[(&str, &str)],
+        ) -> Result<Response<String>, GithubRestError>
+        where
+            T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+            V: Into<Self::Body> + std::marker::Send,
+        {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+
+            if headers.contains(&("If-None-Match", "\"abc\"")) {
+                Ok(Response::new(String::new()).with_status(304))
+            } else {
+                Ok(Response::new("\"octocat\"".to_owned()).with_header("ETag", "\"abc\""))
+            }
+        }
+
+        async fn req<T, V, A: DeserializeOwned>(
+            &self,
+            url: EndPoints,
+            query: Option<&T>,
+            body: Option<V>,
+        ) -> Result<A, GithubRestError>
+        where
+            T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+            V: Into<Self::Body> + std::marker::Send,
+        {
+            Ok(serde_json::from_str(&self.raw_req(url, query, body).await?)?)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_requester() {
+        let client = CachedRequester::new(FakeRequester::default(), MemoryCache::new());
+        let url = || EndPoints::GetUsersusername("octocat".to_owned());
+
+        for _ in 0..2 {
+            let login: String = client.req::<(), String, _>(url(), None, None).await.unwrap();
+            assert_eq!(login, "octocat");
+        }
+
+        let requests = client.inner().requests.lock().unwrap();
+
+        assert!(requests[0].is_empty());
+        assert_eq!(requests[1], vec![("If-None-Match".to_owned(), "\"abc\"".to_owned())]);
+        assert!(client.store().get("/users/octocat").is_some());
+    }
+
+    #[test]
+    fn test_disk_cache() {
+        let dir = std::env::temp_dir().join(format!("octocat-rs-cache-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+
+        let entry = CacheEntry {
+            etag: Some("\"abc\"".to_owned()),
+            last_modified: None,
+            body: "{}".to_owned(),
+        };
+
+        cache.insert("/users/octocat", entry.clone());
+
+        assert_eq!(DiskCache::new(&dir).unwrap().get("/users/octocat"), Some(entry));
+        assert_eq!(cache.get("/users/octocat?page=2"), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -7,16 +7,20 @@ use base64::write::EncoderWriter as Base64Encoder;
 use github_api_octocat::end_points::{EndPoints, Methods};
 use reqwest::header;
 use serde::{de::DeserializeOwned, Serialize};
-use std::{fmt::Display, io::Write};
+use std::{collections::HashMap, fmt::Display, io::Write};
 
-use crate::{GithubRestError, Requester};
+use crate::{GithubRestError, Requester, Response, RetryPolicy, GITHUB_API_URL};
 
 /// A default implementation of the [`Requester`] trait.
 pub struct DefaultRequester {
     client: reqwest::Client,
+    base_url: String,
+    retry_policy: RetryPolicy,
 }
 
 impl DefaultRequester {
+    /// Creates a [`DefaultRequester`] that authenticates using Basic auth.
+    /// `auth` should be formatted as `username:token`.
     pub fn new<T>(auth: T) -> Self
     where
         T: Display,
@@ -29,31 +33,26 @@ impl DefaultRequester {
             write!(encoder, "{auth}").unwrap();
         }
 
-        let mut headers = header::HeaderMap::new();
-        headers.insert(
-            header::USER_AGENT,
-            header::HeaderValue::from_str("tricked.pro/v2").unwrap(),
-        );
-        headers.insert(
-            header::ACCEPT,
-            header::HeaderValue::from_str("application/vnd.github.v3+json").unwrap(),
-        );
-        headers.insert(
-            "X-GitHub-Api-Version",
-            header::HeaderValue::from_str("2022-11-28").unwrap(),
-        );
-        headers.insert(
-            header::AUTHORIZATION,
-            header::HeaderValue::from_str(std::str::from_utf8(&auth_header).expect("Failed to parse authorization!"))
-                .unwrap(),
-        );
-
-        let client = reqwest::Client::builder().default_headers(headers).build().unwrap();
+        Self::with_auth_header(Some(
+            std::str::from_utf8(&auth_header).expect("Failed to parse authorization!"),
+        ))
+    }
 
-        DefaultRequester { client }
+    /// Creates a [`DefaultRequester`] that sends `token` as a bearer token.
+    /// This is how fine-grained personal access tokens and OAuth tokens are
+    /// meant to be sent.
+    pub fn new_bearer<T>(token: T) -> Self
+    where
+        T: Display,
+    {
+        Self::with_auth_header(Some(&format!("Bearer {token}")))
     }
 
     pub fn new_none() -> Self {
+        Self::with_auth_header(None)
+    }
+
+    fn with_auth_header(auth_header: Option<&str>) -> Self {
         let mut headers = header::HeaderMap::new();
         headers.insert(
             header::USER_AGENT,
@@ -64,21 +63,59 @@ impl DefaultRequester {
             header::HeaderValue::from_str("application/vnd.github.v3+json").unwrap(),
         );
 
+        if let Some(auth_header) = auth_header {
+            headers.insert(
+                "X-GitHub-Api-Version",
+                header::HeaderValue::from_str("2022-11-28").unwrap(),
+            );
+            headers.insert(
+                header::AUTHORIZATION,
+                header::HeaderValue::from_str(auth_header).expect("Failed to parse authorization!"),
+            );
+        }
+
         let client = reqwest::Client::builder().default_headers(headers).build().unwrap();
-        DefaultRequester { client }
+
+        DefaultRequester {
+            client,
+            base_url: GITHUB_API_URL.to_owned(),
+            retry_policy: RetryPolicy::default(),
+        }
     }
-}
 
-#[async_trait]
-impl Requester for DefaultRequester {
-    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    /// Sets the base URL that requests are sent to. Default:
+    /// `https://api.github.com`.
+    ///
+    /// For GitHub Enterprise Server, see [`enterprise_api_url`].
+    ///
+    /// [`enterprise_api_url`]: crate::enterprise_api_url
+    pub fn set_base_url<V: Into<String>>(&mut self, base_url: V) {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+    }
+
+    /// Sets the [`RetryPolicy`] used for requests that fail for transient
+    /// reasons. By default, idempotent requests are attempted up to 3 times.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Sends a request using the current [`RetryPolicy`], without checking
+    /// the status code of the response.
+    async fn send<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<reqwest::Response, GithubRestError>
     where
         T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
-        V: Into<Self::Body> + std::marker::Send,
+        V: Into<reqwest::Body> + std::marker::Send,
     {
-        let path = format!("https://api.github.com{}", url.path());
+        let path = format!("{}{path}", self.base_url);
 
-        let mut req = match url.method() {
+        let mut req = match method {
             Methods::Get => self.client.get(path),
             Methods::Post => self.client.post(path),
             Methods::Put => self.client.put(path),
@@ -94,20 +131,105 @@ impl Requester for DefaultRequester {
             req = req.body(body)
         }
 
-        let res = req.send().await?;
-
-        match res.status().as_u16() {
-            200..=299 => {}
-            401 => {
-                return Err(GithubRestError::NotAuthorized(res.text().await?));
-            }
-            _ => {
-                return Err(GithubRestError::ResponseError(res.status(), res.text().await?));
-            }
+        for (name, value) in headers {
+            req = req.header(*name, *value)
         }
-        let txt = res.text().await?;
 
-        Ok(txt)
+        Ok(self.retry_policy.send(&method, req).await?)
+    }
+}
+
+/// Reads the body of `res` as text and checks its status code.
+async fn text_response(res: reqwest::Response) -> Result<Response<String>, GithubRestError> {
+    let (status, headers) = (res.status().as_u16(), response_headers(&res));
+
+    let res = Response {
+        body: res.text().await?,
+        status,
+        headers,
+    };
+
+    res.error_for_status()
+}
+
+fn response_headers(res: &reqwest::Response) -> HashMap<String, String> {
+    res.headers()
+        .iter()
+        .filter_map(|(k, v)| Some((k.as_str().to_owned(), v.to_str().ok()?.to_owned())))
+        .collect()
+}
+
+#[async_trait]
+impl Requester for DefaultRequester {
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        Ok(self.raw_req_with_meta(url, query, body).await?.body)
+    }
+
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        self.raw_req_with_headers(url, query, body, &[]).await
+    }
+
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let res = self.send(url.method(), &url.path(), query, body, headers).await?;
+
+        text_response(res).await
+    }
+
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+    {
+        let res = self
+            .send::<T, String>(url.method(), &url.path(), query, None, &[])
+            .await?;
+        let (status, headers) = (res.status().as_u16(), response_headers(&res));
+
+        let res = Response {
+            body: res.bytes().await?.to_vec(),
+            status,
+            headers,
+        };
+
+        res.error_for_status()
+    }
+
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let res = self.send(method, path, query, body, &[]).await?;
+
+        text_response(res).await
     }
 
     async fn req<T, V, A: DeserializeOwned>(
@@ -124,3 +246,80 @@ impl Requester for DefaultRequester {
         Ok(serde_json::from_str(&r)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::enterprise_api_url;
+
+    #[tokio::test]
+    async fn test_base_url() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Responds with the path of the request as a JSON string
+        std::thread::spawn(move || {
+            let mut stream = listener.incoming().next().unwrap().unwrap();
+            let mut buf = [0; 4096];
+            let n = stream.read(&mut buf).unwrap();
+
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let body = format!("{:?}", request.split(' ').nth(1).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let mut requester = DefaultRequester::new_none();
+        requester.set_base_url(enterprise_api_url(&format!("http://{addr}/")));
+
+        let path = requester
+            .req::<(), String, String>(EndPoints::GetUsersusername("octocat".to_owned()), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(path, "/api/v3/users/octocat");
+        assert_eq!(
+            enterprise_api_url("https://github.example.com/api/v3/"),
+            "https://github.example.com/api/v3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_retry_policy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Only answers a single request, so a retry would fail to connect
+        std::thread::spawn(move || {
+            let mut stream = listener.incoming().next().unwrap().unwrap();
+            let mut buf = [0; 4096];
+            let _ = stream.read(&mut buf).unwrap();
+            let body = r#"{"message":"Service Unavailable"}"#;
+
+            write!(
+                stream,
+                "HTTP/1.1 503 Service Unavailable\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let mut requester = DefaultRequester::new_none();
+        requester.set_base_url(format!("http://{addr}"));
+        requester.set_retry_policy(RetryPolicy::none());
+
+        let res = requester.raw_req::<(), String>(EndPoints::GetZen(), None, None).await;
+
+        assert!(matches!(res, Err(GithubRestError::ResponseError(503, _))), "{res:?}");
+    }
+}
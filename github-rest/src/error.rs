@@ -0,0 +1,227 @@
+//! Contains [`ApiError`], the error document GitHub sends back along with
+//! unsuccessful responses.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{GithubRestError, Response};
+
+/// An error document sent by GitHub.
+///
+/// If the response body is not a JSON error document, [`ApiError::message`]
+/// contains the raw body instead.
+///
+/// See also: <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors>
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiError {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// Describes what went wrong with a single field of a request, mostly sent
+/// along with `422 Unprocessable Entity` responses.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiErrorDetail {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// One of `missing`, `missing_field`, `invalid`, `already_exists`,
+    /// `unprocessable` or `custom`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Only set if `code` is `custom`, or if GitHub sent a plain string
+    /// instead of an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ApiErrorDetail {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Message(String),
+            Detail {
+                resource: Option<String>,
+                field: Option<String>,
+                code: Option<String>,
+                message: Option<String>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Message(message) => Self {
+                message: Some(message),
+                ..Self::default()
+            },
+            Repr::Detail {
+                resource,
+                field,
+                code,
+                message,
+            } => Self {
+                resource,
+                field,
+                code,
+                message,
+            },
+        })
+    }
+}
+
+impl ApiError {
+    /// Parses a response body, falling back to using the raw body as the
+    /// message.
+    pub fn parse(body: String) -> Self {
+        serde_json::from_str(&body).unwrap_or(Self {
+            message: body,
+            ..Self::default()
+        })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        for (i, detail) in self.errors.iter().enumerate() {
+            write!(f, "{}{detail}", if i == 0 { ": " } else { ", " })?;
+        }
+
+        if let Some(url) = &self.documentation_url {
+            write!(f, " ({url})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ApiErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [&self.resource, &self.field]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(".");
+
+        match (location.is_empty(), self.message.as_ref().or(self.code.as_ref())) {
+            (true, Some(text)) => write!(f, "{text}"),
+            (false, Some(text)) => write!(f, "{location} {text}"),
+            (_, None) => write!(f, "{location}"),
+        }
+    }
+}
+
+impl Response<String> {
+    /// Turns an unsuccessful [`Response`] into the matching
+    /// [`GithubRestError`] variant. `304 Not Modified` is not considered an
+    /// error.
+    pub fn error_for_status(self) -> Result<Self, GithubRestError> {
+        if self.is_success() || self.is_not_modified() {
+            return Ok(self);
+        }
+
+        if let Some(e) = self.rate_limited() {
+            return Err(e);
+        }
+
+        let error = ApiError::parse(self.body);
+
+        Err(match self.status {
+            401 => GithubRestError::NotAuthorized(error),
+            403 => GithubRestError::Forbidden(error),
+            404 => GithubRestError::NotFound(error),
+            409 => GithubRestError::Conflict(error),
+            410 => GithubRestError::Gone(error),
+            422 => GithubRestError::Validation(error),
+            status => GithubRestError::ResponseError(status, error),
+        })
+    }
+}
+
+impl Response<Vec<u8>> {
+    /// Same as [`Response::<String>::error_for_status`], for responses
+    /// returned by [`Requester::raw_req_bytes`].
+    ///
+    /// [`Requester::raw_req_bytes`]: crate::Requester::raw_req_bytes
+    pub fn error_for_status(self) -> Result<Self, GithubRestError> {
+        if self.is_success() || self.is_not_modified() {
+            return Ok(self);
+        }
+
+        self.map(|body| String::from_utf8_lossy(&body).into_owned())
+            .error_for_status()
+            .map(|res| res.map(String::into_bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_validation() {
+        let res = Response::new(
+            r#"{
+                "message": "Validation Failed",
+                "errors": [
+                    { "resource": "Issue", "field": "title", "code": "missing_field" },
+                    "Label does not exist"
+                ],
+                "documentation_url": "https://docs.github.com/rest/reference/issues#create-an-issue"
+            }"#
+            .to_owned(),
+        )
+        .with_status(422);
+
+        let error = match res.error_for_status() {
+            Err(GithubRestError::Validation(error)) => error,
+            other => panic!("unexpected result: {other:?}"),
+        };
+
+        assert_eq!(
+            error.errors[0],
+            ApiErrorDetail {
+                resource: Some("Issue".to_owned()),
+                field: Some("title".to_owned()),
+                code: Some("missing_field".to_owned()),
+                message: None,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Validation Failed: Issue.title missing_field, Label does not exist \
+             (https://docs.github.com/rest/reference/issues#create-an-issue)"
+        );
+    }
+
+    #[test]
+    fn test_error_for_status() {
+        let res = Response::new(r#"{"message":"Not Found"}"#.to_owned()).with_status(404);
+
+        assert!(matches!(
+            res.error_for_status(),
+            Err(GithubRestError::NotFound(ApiError { message, .. })) if message == "Not Found"
+        ));
+
+        let res = Response::new("upstream timed out".to_owned()).with_status(504);
+
+        match res.error_for_status() {
+            Err(e @ GithubRestError::ResponseError(504, _)) => {
+                assert_eq!(e.to_string(), "request failed with status 504: upstream timed out")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        assert!(Response::new(String::new()).error_for_status().is_ok());
+    }
+}
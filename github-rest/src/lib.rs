@@ -1,28 +1,53 @@
 #![feature(associated_type_defaults)]
 #![deny(rust_2018_idioms)]
 
-use core::fmt;
 use thiserror::Error;
 
 use async_trait::async_trait;
 pub use github_api_octocat::end_points;
-use github_api_octocat::end_points::EndPoints;
+use github_api_octocat::end_points::{EndPoints, Methods};
 
 #[cfg(not(target_family = "wasm"))]
-use reqwest::{Body, StatusCode};
+use reqwest::Body;
 
 use serde::{de::DeserializeOwned, Serialize};
 #[cfg(target_family = "wasm")]
-use std::num::NonZeroU16;
-#[cfg(target_family = "wasm")]
 use worker::wasm_bindgen::JsValue;
 
 #[cfg(feature = "builders")]
 pub mod builders;
+mod cache;
 #[cfg(feature = "client")]
 pub mod client;
+mod error;
 pub mod methods;
+pub mod mock;
 pub mod model;
+mod response;
+mod retry;
+
+pub use cache::*;
+pub use error::*;
+pub use response::*;
+pub use retry::*;
+
+use crate::model::repositories::workflows::events::nested::CheckRun;
+
+/// The base URL of the public GitHub REST API.
+pub const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Gets the base URL of the REST API of a GitHub Enterprise Server instance,
+/// e.g. `https://github.example.com` becomes
+/// `https://github.example.com/api/v3`.
+pub fn enterprise_api_url(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+
+    if url.ends_with("/api/v3") {
+        url.to_owned()
+    } else {
+        format!("{url}/api/v3")
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum GithubRestError {
@@ -32,22 +57,64 @@ pub enum GithubRestError {
     #[cfg(target_family = "wasm")]
     #[error(transparent)]
     WorkerError(#[from] worker::Error),
-    #[cfg(target_family = "wasm")]
-    ResponseError(NonZeroU16, String),
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
-    #[cfg(not(target_family = "wasm"))]
-    ResponseError(StatusCode, String),
-    NotAuthorized(String),
+    #[error(transparent)]
+    UrlEncodeError(#[from] serde_urlencoded::ser::Error),
+    /// `401 Unauthorized`
+    #[error("not authorized: {0}")]
+    NotAuthorized(ApiError),
+    /// `403 Forbidden`, other than when a rate limit has been exceeded.
+    #[error("forbidden: {0}")]
+    Forbidden(ApiError),
+    /// `404 Not Found`. GitHub also responds with this when the resource
+    /// exists, but the current user is not allowed to see it.
+    #[error("not found: {0}")]
+    NotFound(ApiError),
+    /// `409 Conflict`
+    #[error("conflict: {0}")]
+    Conflict(ApiError),
+    /// `410 Gone`
+    #[error("gone: {0}")]
+    Gone(ApiError),
+    /// `422 Unprocessable Entity`. The fields that failed validation can be
+    /// found in [`ApiError::errors`].
+    #[error("validation failed: {0}")]
+    Validation(ApiError),
+    /// Any other unsuccessful status code.
+    #[error("request failed with status {0}: {1}")]
+    ResponseError(u16, ApiError),
+    /// The rate limit has been exhausted.
+    ///
+    /// `reset_at` is the time at which the primary rate limit resets in UTC
+    /// epoch seconds, and `retry_after` is the number of seconds to wait before
+    /// retrying when a secondary rate limit has been hit.
+    #[error("rate limit exceeded")]
+    RateLimited {
+        reset_at: Option<u64>,
+        retry_after: Option<u64>,
+    },
+    /// Credentials could not be turned into an `Authorization` header, e.g.
+    /// because a private key is invalid.
+    #[error("authentication failed: {0}")]
+    AuthError(String),
+    /// A check run has been created or updated, but adding the rest of its
+    /// annotations failed. `check_run` is the check run as of the last
+    /// successful request, so that it can be updated again or completed.
+    #[error("check run {} was only partially written, adding its annotations failed: {error}", .check_run.id)]
+    PartialCheckRun {
+        check_run: Box<CheckRun>,
+        #[source]
+        error: Box<GithubRestError>,
+    },
+    /// The requester does not support the operation, e.g. reading binary
+    /// responses.
+    #[error("unsupported: {0}")]
+    Unsupported(&'static str),
+    #[error("an unknown error occurred")]
     AnyError(),
 }
 
-impl fmt::Display for GithubRestError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error occurred as you can see")
-    }
-}
-
 #[async_trait]
 pub trait Requester: Send + Sync {
     #[cfg(not(target_family = "wasm"))]
@@ -66,6 +133,104 @@ pub trait Requester: Send + Sync {
         T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
         V: Into<Self::Body> + std::marker::Send;
 
+    /// Same as [`Requester::raw_req`], but also returns the status code and
+    /// headers of the response.
+    ///
+    /// The default implementation calls [`Requester::raw_req`] and returns no
+    /// headers, which means that things like pagination will stop after the
+    /// first page. Implementers should override this where possible.
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        Ok(Response::new(self.raw_req(url, query, body).await?))
+    }
+
+    /// Same as [`Requester::raw_req_with_meta`], but also sends the given
+    /// request headers, e.g. `If-None-Match` for conditional requests.
+    ///
+    /// The default implementation ignores `headers`. Implementers should
+    /// override this where possible.
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let _ = headers;
+        self.raw_req_with_meta(url, query, body).await
+    }
+
+    /// Sends a request without a body and returns the raw bytes of the
+    /// response, e.g. for downloading archives.
+    ///
+    /// The default implementation returns [`GithubRestError::Unsupported`],
+    /// as reading the body through [`Requester::raw_req_with_meta`] would
+    /// corrupt anything that is not valid UTF-8.
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+    {
+        let _ = (url, query);
+        Err(GithubRestError::Unsupported(
+            "binary responses are not supported by this requester",
+        ))
+    }
+
+    /// Sends a request to `path`, which is relative to the base URL, e.g.
+    /// `/repos/octocat/hello-world/actions/caches`. This is an escape hatch
+    /// for endpoints that are missing from [`EndPoints`].
+    ///
+    /// The default implementation returns [`GithubRestError::Unsupported`].
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let _ = (method, path, query, body);
+        Err(GithubRestError::Unsupported(
+            "requests to arbitrary paths are not supported by this requester",
+        ))
+    }
+
+    /// Same as [`Requester::req`], but also returns the status code and
+    /// headers of the response.
+    async fn req_with_meta<T, V, A: DeserializeOwned>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<A>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let res = self.raw_req_with_meta(url, query, body).await?;
+
+        Ok(Response {
+            body: serde_json::from_str(&res.body)?,
+            status: res.status,
+            headers: res.headers,
+        })
+    }
+
     async fn req<T, V, A: DeserializeOwned>(
         &self,
         url: EndPoints,
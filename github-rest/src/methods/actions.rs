@@ -0,0 +1,855 @@
+use std::collections::HashMap;
+
+use crate::model::{
+    actions::{ActionsCaches, Artifact, Artifacts, Job, Jobs, WorkflowRun, WorkflowRuns, Workflows},
+    repositories::workflows::Workflow,
+};
+
+use super::{prelude::*, Pagination};
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/workflows`
+/// * docs <https://docs.github.com/rest/reference/actions#list-repository-workflows>
+///
+/// List repository workflows
+/// Lists the workflows in a repository.
+pub async fn get_workflows<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&Pagination>,
+) -> Result<Workflows, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<Pagination, String, Workflows>(
+            EndPoints::GetReposownerrepoActionsWorkflows(owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/workflows/{workflow_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#get-a-workflow>
+///
+/// Get a workflow
+/// Gets a specific workflow. You can replace `workflow_id` with the workflow
+/// file name. For example, you could use `main.yaml`.
+pub async fn get_workflow<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    workflow_id: impl Into<String>,
+) -> Result<Workflow, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Workflow>(
+            EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(owner.into(), repo.into(), workflow_id.into()),
+            None,
+            None,
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DispatchWorkflowBody {
+    /// **Required**. The git reference for the workflow. The reference can be
+    /// a branch or tag name.
+    #[serde(rename = "ref")]
+    pub reference: String,
+    /// Input keys and values configured in the workflow file. Any default
+    /// properties configured in the workflow file will be used when `inputs`
+    /// are omitted.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub inputs: HashMap<String, String>,
+}
+
+/// * tags actions
+/// * post `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches`
+/// * docs <https://docs.github.com/rest/reference/actions#create-a-workflow-dispatch-event>
+///
+/// Create a workflow dispatch event
+/// You can use this endpoint to manually trigger a GitHub Actions workflow
+/// run. You can replace `workflow_id` with the workflow file name. For
+/// example, you could use `main.yaml`.
+///
+/// You must configure your GitHub Actions workflow to run when the
+/// [`workflow_dispatch` webhook](https://docs.github.com/developers/webhooks-and-events/webhook-events-and-payloads#workflow_dispatch)
+/// event occurs.
+///
+/// GitHub doesn't return the run that gets created. To wait for it, look for
+/// it using [`get_workflow_runs_for_workflow`] with `event` set to
+/// `workflow_dispatch`, then poll [`get_workflow_run`] until
+/// [`WorkflowRun::is_completed`] returns `true`.
+pub async fn dispatch_workflow<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    workflow_id: impl Into<String>,
+    body: &DispatchWorkflowBody,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(
+                owner.into(),
+                repo.into(),
+                workflow_id.into(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetWorkflowRunsBody {
+    /// Returns someone's workflow runs. Use the login for the user who
+    /// created the `push` associated with the check suite or workflow run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// Returns workflow runs associated with a branch. Use the name of the
+    /// branch of the `push`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Returns workflow run triggered by the event you specify. For example,
+    /// `push`, `pull_request` or `issue`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Returns workflow runs with the check run `status` or `conclusion` that
+    /// you specify. For example, a conclusion can be `success` or a status
+    /// can be `in_progress`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Returns workflow runs created within the given date-time range, e.g.
+    /// `>=2023-06-01T00:00:00Z`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Only returns workflow runs that are associated with the specified
+    /// `head_sha`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_sha: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/runs`
+/// * docs <https://docs.github.com/rest/reference/actions#list-workflow-runs-for-a-repository>
+///
+/// List workflow runs for a repository
+/// Lists all workflow runs for a repository. You can use parameters to narrow
+/// the list of results.
+pub async fn get_workflow_runs<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetWorkflowRunsBody>,
+) -> Result<WorkflowRuns, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetWorkflowRunsBody, String, WorkflowRuns>(
+            EndPoints::GetReposownerrepoActionsRuns(owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_workflow_runs`], but returns a stream over every page of
+/// results.
+pub fn get_workflow_runs_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetWorkflowRunsBody>,
+) -> Paginated<'a, WorkflowRuns>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoActionsRuns(owner.clone(), repo.clone()),
+        options,
+    )
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs`
+/// * docs <https://docs.github.com/rest/reference/actions#list-workflow-runs>
+///
+/// List workflow runs for a workflow
+/// List all workflow runs for a workflow. You can replace `workflow_id` with
+/// the workflow file name. For example, you could use `main.yaml`.
+pub async fn get_workflow_runs_for_workflow<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    workflow_id: impl Into<String>,
+    options: Option<&GetWorkflowRunsBody>,
+) -> Result<WorkflowRuns, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetWorkflowRunsBody, String, WorkflowRuns>(
+            EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(owner.into(), repo.into(), workflow_id.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/runs/{run_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#get-a-workflow-run>
+///
+/// Get a workflow run
+/// Gets a specific workflow run.
+pub async fn get_workflow_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+) -> Result<WorkflowRun, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, WorkflowRun>(
+            EndPoints::GetReposownerrepoActionsRunsrunId(owner.into(), repo.into(), run_id.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RerunWorkflowRunBody {
+    /// Whether to enable debug logging for the re-run.
+    /// Default: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_debug_logging: Option<bool>,
+}
+
+/// * tags actions
+/// * post `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun`
+/// * docs <https://docs.github.com/rest/reference/actions#re-run-a-workflow>
+///
+/// Re-run a workflow
+/// Re-runs your workflow run using its `id`.
+pub async fn rerun_workflow_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+    body: &RerunWorkflowRunBody,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::PostReposownerrepoActionsRunsrunIdRerun(owner.into(), repo.into(), run_id.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags actions
+/// * post `/repos/{owner}/{repo}/actions/runs/{run_id}/cancel`
+/// * docs <https://docs.github.com/rest/reference/actions#cancel-a-workflow-run>
+///
+/// Cancel a workflow run
+/// Cancels a workflow run using its `id`.
+pub async fn cancel_workflow_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::PostReposownerrepoActionsRunsrunIdCancel(owner.into(), repo.into(), run_id.to_string()),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/runs/{run_id}/logs`
+/// * docs <https://docs.github.com/rest/reference/actions#download-workflow-run-logs>
+///
+/// Download workflow run logs
+/// Gets a zip archive containing the logs of every job of a workflow run.
+pub async fn download_workflow_run_logs<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+) -> Result<Vec<u8>, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_bytes::<String>(
+            EndPoints::GetReposownerrepoActionsRunsrunIdLogs(owner.into(), repo.into(), run_id.to_string()),
+            None,
+        )
+        .await?;
+
+    Ok(res.body)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetJobsBody {
+    /// Filters jobs by their `completed_at` timestamp. Either `latest`, which
+    /// returns jobs from the most recent execution of the workflow run, or
+    /// `all`.
+    /// Default: latest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/runs/{run_id}/jobs`
+/// * docs <https://docs.github.com/rest/reference/actions#list-jobs-for-a-workflow-run>
+///
+/// List jobs for a workflow run
+/// Lists jobs for a workflow run. You can use parameters to narrow the list
+/// of results.
+pub async fn get_workflow_run_jobs<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+    options: Option<&GetJobsBody>,
+) -> Result<Jobs, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetJobsBody, String, Jobs>(
+            EndPoints::GetReposownerrepoActionsRunsrunIdJobs(owner.into(), repo.into(), run_id.to_string()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/jobs/{job_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#get-a-job-for-a-workflow-run>
+///
+/// Get a job for a workflow run
+/// Gets a specific job in a workflow run.
+pub async fn get_job<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    job_id: i64,
+) -> Result<Job, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Job>(
+            EndPoints::GetReposownerrepoActionsJobsjobId(owner.into(), repo.into(), job_id.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/jobs/{job_id}/logs`
+/// * docs <https://docs.github.com/rest/reference/actions#download-job-logs-for-a-workflow-run>
+///
+/// Download job logs for a workflow run
+/// Gets the logs of a job. GitHub redirects to a short-lived download URL,
+/// which is followed automatically.
+pub async fn download_job_logs<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    job_id: i64,
+) -> Result<Vec<u8>, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_bytes::<String>(
+            EndPoints::GetReposownerrepoActionsJobsjobIdLogs(owner.into(), repo.into(), job_id.to_string()),
+            None,
+        )
+        .await?;
+
+    Ok(res.body)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetArtifactsBody {
+    /// Filters artifacts by exact match on their name field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/artifacts`
+/// * docs <https://docs.github.com/rest/reference/actions#list-artifacts-for-a-repository>
+///
+/// List artifacts for a repository
+/// Lists all artifacts for a repository.
+pub async fn get_artifacts<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetArtifactsBody>,
+) -> Result<Artifacts, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetArtifactsBody, String, Artifacts>(
+            EndPoints::GetReposownerrepoActionsArtifacts(owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts`
+/// * docs <https://docs.github.com/rest/reference/actions#list-workflow-run-artifacts>
+///
+/// List workflow run artifacts
+/// Lists artifacts for a workflow run.
+pub async fn get_workflow_run_artifacts<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    run_id: i64,
+    options: Option<&GetArtifactsBody>,
+) -> Result<Artifacts, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetArtifactsBody, String, Artifacts>(
+            EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(owner.into(), repo.into(), run_id.to_string()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#get-an-artifact>
+///
+/// Get an artifact
+/// Gets a specific artifact for a workflow run.
+pub async fn get_artifact<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    artifact_id: i64,
+) -> Result<Artifact, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Artifact>(
+            EndPoints::GetReposownerrepoActionsArtifactsartifactId(owner.into(), repo.into(), artifact_id.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+/// * tags actions
+/// * delete `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#delete-an-artifact>
+///
+/// Delete an artifact
+/// Deletes an artifact for a workflow run.
+pub async fn delete_artifact<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    artifact_id: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(
+                owner.into(),
+                repo.into(),
+                artifact_id.to_string(),
+            ),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/
+///   {archive_format}`
+/// * docs <https://docs.github.com/rest/reference/actions#download-an-artifact>
+///
+/// Download an artifact
+/// Gets a zip archive of an artifact.
+pub async fn download_artifact<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    artifact_id: i64,
+) -> Result<Vec<u8>, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_bytes::<String>(
+            EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(
+                owner.into(),
+                repo.into(),
+                artifact_id.to_string(),
+                "zip".to_owned(),
+            ),
+            None,
+        )
+        .await?;
+
+    Ok(res.body)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetActionsCachesBody {
+    /// An explicit key or prefix for identifying the cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The full git reference for narrowing down the cache, e.g.
+    /// `refs/heads/main`.
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_field: Option<String>,
+    /// The property to sort the results by. One of `created_at`,
+    /// `last_accessed_at` or `size_in_bytes`.
+    /// Default: last_accessed_at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// The direction to sort the results by. One of `asc` or `desc`.
+    /// Default: desc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags actions
+/// * get `/repos/{owner}/{repo}/actions/caches`
+/// * docs <https://docs.github.com/rest/reference/actions#list-github-actions-caches-for-a-repository>
+///
+/// List GitHub Actions caches for a repository
+/// Lists the GitHub Actions caches for a repository.
+pub async fn get_actions_caches<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetActionsCachesBody>,
+) -> Result<ActionsCaches, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_path::<GetActionsCachesBody, String>(
+            Methods::Get,
+            &format!("/repos/{}/{}/actions/caches", owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await?;
+
+    Ok(serde_json::from_str(&res.body)?)
+}
+
+/// * tags actions
+/// * delete `/repos/{owner}/{repo}/actions/caches/{cache_id}`
+/// * docs <https://docs.github.com/rest/reference/actions#delete-a-github-actions-cache-for-a-repository-using-a-cache-id>
+///
+/// Delete a GitHub Actions cache for a repository (using a cache ID)
+/// Deletes a GitHub Actions cache for a repository, using a cache ID.
+pub async fn delete_actions_cache<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    cache_id: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req_path::<String, String>(
+            Methods::Delete,
+            &format!("/repos/{}/{}/actions/caches/{cache_id}", owner.into(), repo.into()),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags actions
+/// * delete `/repos/{owner}/{repo}/actions/caches`
+/// * docs <https://docs.github.com/rest/reference/actions#delete-github-actions-caches-for-a-repository-using-a-cache-key>
+///
+/// Delete GitHub Actions caches for a repository (using a cache key)
+/// Deletes one or more GitHub Actions caches for a repository, using a
+/// complete cache key. By default, all caches that match the provided key are
+/// deleted, but you can optionally provide a git ref to restrict deletions to
+/// caches that match both the provided key and the git ref. Returns the
+/// deleted caches.
+pub async fn delete_actions_caches_by_key<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    key: impl Into<String>,
+    ref_field: Option<String>,
+) -> Result<ActionsCaches, GithubRestError>
+where
+    T: Requester,
+{
+    let query = GetActionsCachesBody {
+        key: Some(key.into()),
+        ref_field,
+        ..GetActionsCachesBody::default()
+    };
+
+    let res = client
+        .raw_req_path::<GetActionsCachesBody, String>(
+            Methods::Delete,
+            &format!("/repos/{}/{}/actions/caches", owner.into(), repo.into()),
+            Some(&query),
+            None,
+        )
+        .await?;
+
+    Ok(serde_json::from_str(&res.body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        mock::{Interaction, MockRequester},
+        model::actions::{JobStatus, WorkflowRunConclusion},
+    };
+
+    #[tokio::test]
+    async fn test_get_workflow_run() {
+        let endpoint = EndPoints::GetReposownerrepoActionsRunsrunId(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "30433642".to_owned(),
+        );
+        let url = "https://api.github.com/repos/octocat-rs/octocat-rs/actions/runs/30433642";
+
+        let client = MockRequester::new().with(Interaction::new(endpoint).respond_with(&json!({
+            "id": 30433642,
+            "name": "Release",
+            "node_id": "MDEyOldvcmtmbG93IFJ1bjI2OTI4OQ==",
+            "head_branch": "main",
+            "head_sha": "acb5820ced9479c074f688cc328bf03f341a511d",
+            "run_number": 562,
+            "event": "workflow_dispatch",
+            "status": "completed",
+            "conclusion": "success",
+            "workflow_id": 159038,
+            "url": url,
+            "html_url": "https://github.com/octocat-rs/octocat-rs/actions/runs/30433642",
+            "created_at": "2023-06-01T00:00:00Z",
+            "updated_at": "2023-06-01T00:05:00Z",
+            "jobs_url": format!("{url}/jobs"),
+            "logs_url": format!("{url}/logs"),
+            "check_suite_url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-suites/414944374",
+            "artifacts_url": format!("{url}/artifacts"),
+            "cancel_url": format!("{url}/cancel"),
+            "rerun_url": format!("{url}/rerun"),
+            "workflow_url": "https://api.github.com/repos/octocat-rs/octocat-rs/actions/workflows/159038",
+            "head_commit": null
+        })));
+
+        let run = get_workflow_run(&client, "octocat-rs", "octocat-rs", 30433642)
+            .await
+            .unwrap();
+
+        assert!(run.is_completed());
+        assert_eq!(run.conclusion, Some(WorkflowRunConclusion::Success));
+    }
+
+    #[tokio::test]
+    async fn test_get_job() {
+        let endpoint = EndPoints::GetReposownerrepoActionsJobsjobId(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "399444496".to_owned(),
+        );
+
+        let client = MockRequester::new().with(Interaction::new(endpoint).respond_with(&json!({
+            "id": 399444496,
+            "run_id": 29679449,
+            "run_url": "https://api.github.com/repos/octocat-rs/octocat-rs/actions/runs/29679449",
+            "node_id": "MDEyOldvcmtmbG93IEpvYjM5OTQ0NDQ5Ng==",
+            "head_sha": "f83a356604ae3c5d03e1b46ef4d1ca77d64a90b0",
+            "url": "https://api.github.com/repos/octocat-rs/octocat-rs/actions/jobs/399444496",
+            "html_url": "https://github.com/octocat-rs/octocat-rs/runs/399444496",
+            "status": "pending",
+            "conclusion": null,
+            "started_at": "2023-06-01T00:00:00Z",
+            "completed_at": null,
+            "name": "build",
+            "steps": [{
+                "name": "Set up job",
+                "status": "completed",
+                "conclusion": "success",
+                "number": 1,
+                "started_at": "2023-06-01T00:00:00Z",
+                "completed_at": "2023-06-01T00:00:05Z"
+            }],
+            "check_run_url": "https://api.github.com/repos/octocat-rs/octocat-rs/check-runs/399444496",
+            "labels": ["ubuntu-latest"],
+            "runner_id": null,
+            "runner_name": null,
+            "runner_group_id": null,
+            "runner_group_name": null
+        })));
+
+        let job = get_job(&client, "octocat-rs", "octocat-rs", 399444496).await.unwrap();
+
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.conclusion, None);
+        assert_eq!(job.steps[0].status, JobStatus::Completed);
+        assert_eq!(job.steps[0].conclusion, Some(WorkflowRunConclusion::Success));
+    }
+
+    #[tokio::test]
+    async fn test_actions_caches() {
+        let path = "/repos/octocat-rs/octocat-rs/actions/caches";
+        let caches = json!({
+            "total_count": 1,
+            "actions_caches": [{
+                "id": 505,
+                "ref": "refs/heads/main",
+                "key": "Linux-node-958aff96db2d75d67787d1e634ae70b659de937b",
+                "version": "73885106f58cc52a7df9ec4d4a5622a5614813162cb516c759a30af6bf56e6f0",
+                "last_accessed_at": "2023-06-01T00:00:00Z",
+                "created_at": "2023-05-30T00:00:00Z",
+                "size_in_bytes": 1024
+            }]
+        });
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::path(Methods::Get, path)
+                    .query(&[("ref", "refs/heads/main")])
+                    .respond_with(&caches),
+            )
+            .with(
+                Interaction::path(Methods::Delete, path)
+                    .query(&[("key", "Linux-node-958aff96db2d75d67787d1e634ae70b659de937b")])
+                    .respond_with(&caches),
+            )
+            .with(
+                Interaction::path(Methods::Delete, &format!("{path}/505"))
+                    .status(204)
+                    .respond_with_text(""),
+            );
+
+        let options = GetActionsCachesBody {
+            ref_field: Some("refs/heads/main".to_owned()),
+            ..GetActionsCachesBody::default()
+        };
+        let listed = get_actions_caches(&client, "octocat-rs", "octocat-rs", Some(&options))
+            .await
+            .unwrap();
+
+        assert_eq!(listed.total_count, 1);
+        assert_eq!(listed.actions_caches[0].ref_field, "refs/heads/main");
+        assert_eq!(listed.actions_caches[0].size_in_bytes, 1024);
+
+        let deleted = delete_actions_caches_by_key(
+            &client,
+            "octocat-rs",
+            "octocat-rs",
+            "Linux-node-958aff96db2d75d67787d1e634ae70b659de937b",
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(deleted, listed);
+
+        delete_actions_cache(&client, "octocat-rs", "octocat-rs", 505)
+            .await
+            .unwrap();
+
+        assert_eq!(client.requests().len(), 3);
+    }
+}
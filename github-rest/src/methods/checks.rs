@@ -0,0 +1,430 @@
+use std::iter;
+
+use crate::model::{
+    checks::{AnnotationLevel, CheckRunConclusion, CheckRunStatus, CheckRuns},
+    repositories::workflows::events::nested::CheckRun,
+};
+
+use super::prelude::*;
+
+/// The maximum number of annotations GitHub accepts per request. Check run
+/// outputs with more annotations are sent using several requests.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CheckRunAnnotationBody {
+    /// **Required**. The path of the file to add an annotation to. For
+    /// example, `assets/css/main.css`.
+    pub path: String,
+    /// **Required**. The start line of the annotation.
+    pub start_line: i64,
+    /// **Required**. The end line of the annotation.
+    pub end_line: i64,
+    /// The start column of the annotation. Annotations only support
+    /// `start_column` and `end_column` on the same line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<i64>,
+    /// The end column of the annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<i64>,
+    /// **Required**. The level of the annotation.
+    pub annotation_level: AnnotationLevel,
+    /// **Required**. A short description of the feedback for these lines of
+    /// code. The maximum size is 64 KB.
+    pub message: String,
+    /// The title that represents the annotation. The maximum size is 255
+    /// characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Details about this annotation. The maximum size is 64 KB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+}
+
+impl CheckRunAnnotationBody {
+    pub fn new(
+        path: impl Into<String>,
+        start_line: i64,
+        end_line: i64,
+        annotation_level: AnnotationLevel,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            start_line,
+            end_line,
+            annotation_level,
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CheckRunImageBody {
+    /// **Required**. The alternative text for the image.
+    pub alt: String,
+    /// **Required**. The full URL of the image.
+    pub image_url: String,
+    /// A short image description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// A button displayed on GitHub, which sends a `requested_action` check run
+/// event to the app when clicked.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CheckRunActionBody {
+    /// **Required**. The text displayed on the button. The maximum size is 20
+    /// characters.
+    pub label: String,
+    /// **Required**. A short explanation of what this action would do. The
+    /// maximum size is 40 characters.
+    pub description: String,
+    /// **Required**. A reference for the action, sent back in the
+    /// `requested_action` payload. The maximum size is 20 characters.
+    pub identifier: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CheckRunOutputBody {
+    /// **Required**. The title of the check run.
+    pub title: String,
+    /// **Required**. The summary of the check run. This parameter supports
+    /// Markdown.
+    pub summary: String,
+    /// The details of the check run. This parameter supports Markdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Annotations to add to the check run. Any number of annotations can be
+    /// passed here, they are sent in batches of
+    /// [`MAX_ANNOTATIONS_PER_REQUEST`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CheckRunAnnotationBody>,
+    /// Images to display in the check run.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<CheckRunImageBody>,
+}
+
+impl CheckRunOutputBody {
+    /// Splits the output into outputs with at most
+    /// [`MAX_ANNOTATIONS_PER_REQUEST`] annotations each. Images are only
+    /// part of the first one.
+    fn batches(&self) -> impl Iterator<Item = CheckRunOutputBody> + '_ {
+        let mut chunks = self.annotations.chunks(MAX_ANNOTATIONS_PER_REQUEST);
+        let output = |annotations: &[CheckRunAnnotationBody], images: Vec<CheckRunImageBody>| CheckRunOutputBody {
+            title: self.title.clone(),
+            summary: self.summary.clone(),
+            text: self.text.clone(),
+            annotations: annotations.to_vec(),
+            images,
+        };
+
+        let first = output(chunks.next().unwrap_or_default(), self.images.clone());
+
+        iter::once(first).chain(chunks.map(move |chunk| output(chunk, Vec::new())))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CreateCheckRunBody {
+    /// **Required**. The name of the check. For example, "code-coverage".
+    pub name: String,
+    /// **Required**. The SHA of the commit.
+    pub head_sha: String,
+    /// The URL of the integrator's site that has the full details of the
+    /// check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    /// A reference for the run on the integrator's system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Default: queued
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// The time that the check run began. This is a timestamp in ISO 8601
+    /// format: YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// **Required if you provide `completed_at` or a `status` of
+    /// `completed`**.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckRunConclusion>,
+    /// The time the check completed. This is a timestamp in ISO 8601 format:
+    /// YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutputBody>,
+    /// Up to 3 buttons to display on GitHub.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CheckRunActionBody>,
+}
+
+/// * tags checks
+/// * post `/repos/{owner}/{repo}/check-runs`
+/// * docs <https://docs.github.com/rest/reference/checks#create-a-check-run>
+///
+/// Create a check run
+/// Creates a new check run for a specific commit in a repository. Your GitHub
+/// App must have the `checks:write` permission to create check runs.
+///
+/// If the output has more than [`MAX_ANNOTATIONS_PER_REQUEST`] annotations,
+/// the remaining ones are added by updating the check run afterwards. If one
+/// of these updates fails, [`GithubRestError::PartialCheckRun`] is returned,
+/// which contains the check run that has already been created.
+pub async fn create_check_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    body: &CreateCheckRunBody,
+) -> Result<CheckRun, GithubRestError>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+    let mut batches = body.output.iter().flat_map(CheckRunOutputBody::batches);
+
+    let first = CreateCheckRunBody {
+        output: batches.next(),
+        ..body.clone()
+    };
+
+    let mut check_run = client
+        .req::<String, String, CheckRun>(
+            EndPoints::PostReposownerrepoCheckRuns(owner.clone(), repo.clone()),
+            None,
+            Some(serde_json::to_string(&first)?),
+        )
+        .await?;
+
+    for output in batches {
+        let body = UpdateCheckRunBody {
+            output: Some(output),
+            ..Default::default()
+        };
+
+        check_run = match patch_check_run(client, &owner, &repo, check_run.id, &body).await {
+            Ok(check_run) => check_run,
+            Err(error) => {
+                return Err(GithubRestError::PartialCheckRun {
+                    check_run: Box::new(check_run),
+                    error: Box::new(error),
+                })
+            }
+        };
+    }
+
+    Ok(check_run)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UpdateCheckRunBody {
+    /// The name of the check. For example, "code-coverage".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The URL of the integrator's site that has the full details of the
+    /// check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    /// A reference for the run on the integrator's system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// This is a timestamp in ISO 8601 format: YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// **Required if you provide `completed_at` or a `status` of
+    /// `completed`**.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckRunConclusion>,
+    /// The time the check completed. This is a timestamp in ISO 8601 format:
+    /// YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// Annotations are appended to the ones the check run already has.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutputBody>,
+    /// Up to 3 buttons to display on GitHub. Replaces the current ones.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CheckRunActionBody>,
+}
+
+/// * tags checks
+/// * patch `/repos/{owner}/{repo}/check-runs/{check_run_id}`
+/// * docs <https://docs.github.com/rest/reference/checks#update-a-check-run>
+///
+/// Update a check run
+/// Updates a check run for a specific commit in a repository. Your GitHub App
+/// must have the `checks:write` permission to edit check runs.
+///
+/// If the output has more than [`MAX_ANNOTATIONS_PER_REQUEST`] annotations,
+/// they are sent using several requests. If one of the requests after the
+/// first fails, [`GithubRestError::PartialCheckRun`] is returned, which
+/// contains the check run as of the last successful request. Its annotations
+/// have already been added, so retrying the whole update would duplicate them.
+pub async fn update_check_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    check_run_id: i64,
+    body: &UpdateCheckRunBody,
+) -> Result<CheckRun, GithubRestError>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+    let mut batches = body.output.iter().flat_map(CheckRunOutputBody::batches);
+
+    let first = UpdateCheckRunBody {
+        output: batches.next(),
+        ..body.clone()
+    };
+
+    let mut check_run = patch_check_run(client, &owner, &repo, check_run_id, &first).await?;
+
+    for output in batches {
+        let body = UpdateCheckRunBody {
+            output: Some(output),
+            ..Default::default()
+        };
+
+        check_run = match patch_check_run(client, &owner, &repo, check_run_id, &body).await {
+            Ok(check_run) => check_run,
+            Err(error) => {
+                return Err(GithubRestError::PartialCheckRun {
+                    check_run: Box::new(check_run),
+                    error: Box::new(error),
+                })
+            }
+        };
+    }
+
+    Ok(check_run)
+}
+
+async fn patch_check_run<T>(
+    client: &T,
+    owner: &str,
+    repo: &str,
+    check_run_id: i64,
+    body: &UpdateCheckRunBody,
+) -> Result<CheckRun, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, CheckRun>(
+            EndPoints::PatchReposownerrepoCheckRunscheckRunId(
+                owner.to_owned(),
+                repo.to_owned(),
+                check_run_id.to_string(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags checks
+/// * get `/repos/{owner}/{repo}/check-runs/{check_run_id}`
+/// * docs <https://docs.github.com/rest/reference/checks#get-a-check-run>
+///
+/// Get a check run
+/// Gets a single check run using its `id`.
+pub async fn get_check_run<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    check_run_id: i64,
+) -> Result<CheckRun, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, CheckRun>(
+            EndPoints::GetReposownerrepoCheckRunscheckRunId(owner.into(), repo.into(), check_run_id.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetCheckRunsBody {
+    /// Returns check runs with the specified `name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_name: Option<String>,
+    /// Returns check runs with the specified `status`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// Filters check runs by their `completed_at` timestamp. Either `latest`
+    /// or `all`.
+    /// Default: latest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags checks
+/// * get `/repos/{owner}/{repo}/commits/{ref}/check-runs`
+/// * docs <https://docs.github.com/rest/reference/checks#list-check-runs-for-a-git-reference>
+///
+/// List check runs for a Git reference
+/// Lists check runs for a commit ref. The `ref` can be a SHA, branch name, or
+/// a tag name.
+pub async fn get_check_runs<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    reference: impl Into<String>,
+    options: Option<&GetCheckRunsBody>,
+) -> Result<CheckRuns, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetCheckRunsBody, String, CheckRuns>(
+            EndPoints::GetReposownerrepoCommitsrefCheckRuns(owner.into(), repo.into(), reference.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags checks
+/// * get `/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs`
+/// * docs <https://docs.github.com/rest/reference/checks#list-check-runs-in-a-check-suite>
+///
+/// List check runs in a check suite
+/// Lists check runs for a check suite using its `id`.
+pub async fn get_check_runs_in_suite<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    check_suite_id: i64,
+    options: Option<&GetCheckRunsBody>,
+) -> Result<CheckRuns, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetCheckRunsBody, String, CheckRuns>(
+            EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(
+                owner.into(),
+                repo.into(),
+                check_suite_id.to_string(),
+            ),
+            options,
+            None,
+        )
+        .await
+}
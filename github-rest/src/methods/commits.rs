@@ -114,6 +114,25 @@ where
         .await
 }
 
+/// Same as [`get_commits`], but returns a stream over every page of results.
+pub fn get_commits_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetCommitsBody>,
+) -> Paginated<'a, Commits>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoCommits(owner.clone(), repo.clone()),
+        options,
+    )
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct CommitCommentBody {
     /// **Required**. The contents of the comment.
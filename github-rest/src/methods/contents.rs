@@ -0,0 +1,350 @@
+use crate::model::repositories::contents::{Content, Contents, FileCommit};
+
+use super::prelude::*;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GetContentsBody {
+    /// The name of the commit/branch/tag. Default: the repository’s default
+    /// branch (usually master).
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+/// * tags repos
+/// * get `/repos/{owner}/{repo}/contents/{path}`
+/// * docs <https://docs.github.com/rest/reference/repos#get-repository-content>
+///
+/// Get repository content
+/// Gets the contents of a file or directory in a repository. Specify the file
+/// path or directory in `:path`. If you omit `:path`, you will receive the
+/// contents of the repository's root directory.
+///
+/// Files are returned as [`Contents::File`], with their content encoded using
+/// Base64. See [`Content::decode_content`].
+pub async fn get_contents<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    path: impl Into<String>,
+    options: Option<&GetContentsBody>,
+) -> Result<Contents, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetContentsBody, String, Contents>(
+            EndPoints::GetReposownerrepoContentspath(owner.into(), repo.into(), path.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags repos
+/// * get `/repos/{owner}/{repo}/readme`
+/// * docs <https://docs.github.com/rest/reference/repos#get-a-repository-readme>
+///
+/// Get a repository README
+/// Gets the preferred README for a repository.
+pub async fn get_readme<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetContentsBody>,
+) -> Result<Content, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetContentsBody, String, Content>(
+            EndPoints::GetReposownerrepoReadme(owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// * tags repos
+/// * get `/repos/{owner}/{repo}/readme/{dir}`
+/// * docs <https://docs.github.com/rest/reference/repos#get-a-repository-directory-readme>
+///
+/// Get a repository README for a directory
+/// Gets the README from a repository directory.
+pub async fn get_readme_in_directory<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    dir: impl Into<String>,
+    options: Option<&GetContentsBody>,
+) -> Result<Content, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetContentsBody, String, Content>(
+            EndPoints::GetReposownerrepoReadmedir(owner.into(), repo.into(), dir.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// The author or committer of a commit created through the contents API.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CommitAuthorBody {
+    pub name: String,
+    pub email: String,
+    /// A timestamp in ISO 8601 format: YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CreateOrUpdateFileBody {
+    /// **Required**. The commit message.
+    pub message: String,
+    /// **Required**. The new file content, using Base64 encoding.
+    pub content: String,
+    /// **Required if you are updating a file**. The blob SHA of the file being
+    /// replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    /// The branch name. Default: the repository’s default branch (usually
+    /// master).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// The person that committed the file. Default: the authenticated user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitAuthorBody>,
+    /// The author of the file. Default: the `committer` or the authenticated
+    /// user if you omit `committer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitAuthorBody>,
+}
+
+#[cfg(feature = "client")]
+impl CreateOrUpdateFileBody {
+    /// Sets [`CreateOrUpdateFileBody::content`] by encoding `content` using
+    /// Base64.
+    pub fn set_content<C: AsRef<[u8]>>(&mut self, content: C) {
+        use base64::Engine;
+
+        self.content = base64::engine::general_purpose::STANDARD.encode(content);
+    }
+}
+
+/// * tags repos
+/// * put `/repos/{owner}/{repo}/contents/{path}`
+/// * docs <https://docs.github.com/rest/reference/repos#create-or-update-file-contents>
+///
+/// Create or update file contents
+/// Creates a new file or replaces an existing file in a repository.
+///
+/// ```rust,ignore
+/// let changelog = get_contents(&client, "octocat-rs", "octocat-rs", "CHANGELOG.md", None).await?;
+/// let changelog = changelog.file().unwrap();
+///
+/// let mut body = CreateOrUpdateFileBody {
+///     message: format!("Release {}", event.release.tag_name),
+///     sha: Some(changelog.sha.clone()),
+///     ..Default::default()
+/// };
+/// body.set_content(format!("{}\n{notes}", changelog.decode_content_string().unwrap()));
+///
+/// create_or_update_file(&client, "octocat-rs", "octocat-rs", "CHANGELOG.md", &body).await?;
+/// ```
+pub async fn create_or_update_file<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    path: impl Into<String>,
+    options: &CreateOrUpdateFileBody,
+) -> Result<FileCommit, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, FileCommit>(
+            EndPoints::PutReposownerrepoContentspath(owner.into(), repo.into(), path.into()),
+            None,
+            Some(serde_json::to_string(options)?),
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DeleteFileBody {
+    /// **Required**. The commit message.
+    pub message: String,
+    /// **Required**. The blob SHA of the file being deleted.
+    pub sha: String,
+    /// The branch name. Default: the repository’s default branch (usually
+    /// master).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// The person that committed the file. Default: the authenticated user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitAuthorBody>,
+    /// The author of the file. Default: the `committer` or the authenticated
+    /// user if you omit `committer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitAuthorBody>,
+}
+
+/// * tags repos
+/// * delete `/repos/{owner}/{repo}/contents/{path}`
+/// * docs <https://docs.github.com/rest/reference/repos#delete-a-file>
+///
+/// Delete a file
+/// Deletes a file in a repository.
+///
+/// The `author` section is optional and is filled in with the `committer`
+/// information if omitted. If the `committer` information is omitted, the
+/// authenticated user's information is used.
+pub async fn delete_file<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    path: impl Into<String>,
+    options: &DeleteFileBody,
+) -> Result<FileCommit, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, FileCommit>(
+            EndPoints::DeleteReposownerrepoContentspath(owner.into(), repo.into(), path.into()),
+            None,
+            Some(serde_json::to_string(options)?),
+        )
+        .await
+}
+
+/// * tags repos
+/// * get `/repos/{owner}/{repo}/tarball/{ref}`
+/// * docs <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
+///
+/// Download a repository archive (tar)
+/// Gets a gzipped tar archive of the repository at `reference`, which can be
+/// the name of a branch or tag, or a commit SHA.
+pub async fn download_tarball<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    reference: impl Into<String>,
+) -> Result<Vec<u8>, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_bytes::<String>(
+            EndPoints::GetReposownerrepoTarballref(owner.into(), repo.into(), reference.into()),
+            None,
+        )
+        .await?;
+
+    Ok(res.body)
+}
+
+/// * tags repos
+/// * get `/repos/{owner}/{repo}/zipball/{ref}`
+/// * docs <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
+///
+/// Download a repository archive (zip)
+/// Gets a zip archive of the repository at `reference`, which can be the name
+/// of a branch or tag, or a commit SHA.
+pub async fn download_zipball<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    reference: impl Into<String>,
+) -> Result<Vec<u8>, GithubRestError>
+where
+    T: Requester,
+{
+    let res = client
+        .raw_req_bytes::<String>(
+            EndPoints::GetReposownerrepoZipballref(owner.into(), repo.into(), reference.into()),
+            None,
+        )
+        .await?;
+
+    Ok(res.body)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::mock::{Interaction, MockRequester};
+
+    #[tokio::test]
+    async fn test_delete_file() {
+        let endpoint = EndPoints::DeleteReposownerrepoContentspath(
+            "octocat-rs".to_owned(),
+            "octocat-rs".to_owned(),
+            "CHANGELOG.md".to_owned(),
+        );
+        let user = json!({ "name": "octocat", "email": "octocat@github.com", "date": "2023-06-01T00:00:00Z" });
+
+        let client = MockRequester::new().with(
+            Interaction::new(endpoint)
+                .body(&json!({ "message": "Remove changelog", "sha": "3d21ec53" }))
+                .respond_with(&json!({
+                    "content": null,
+                    "commit": {
+                        "sha": "7638417d",
+                        "node_id": "MDY6Q29tbWl0NzYzODQxN2Q=",
+                        "url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/commits/7638417d",
+                        "html_url": "https://github.com/octocat-rs/octocat-rs/commit/7638417d",
+                        "author": user,
+                        "committer": user,
+                        "message": "Remove changelog",
+                        "tree": { "sha": "691272480", "url": "https://api.github.com/repos/octocat-rs/octocat-rs/git/trees/691272480" },
+                        "parents": []
+                    }
+                })),
+        );
+
+        let body = DeleteFileBody {
+            message: "Remove changelog".to_owned(),
+            sha: "3d21ec53".to_owned(),
+            ..Default::default()
+        };
+
+        let res = delete_file(&client, "octocat-rs", "octocat-rs", "CHANGELOG.md", &body)
+            .await
+            .unwrap();
+
+        assert!(res.content.is_none());
+        assert_eq!(res.commit.author.name, "octocat");
+        assert_eq!(res.commit.message, "Remove changelog");
+    }
+
+    #[tokio::test]
+    async fn test_download_tarball() {
+        let endpoint = |reference: &str| {
+            EndPoints::GetReposownerrepoTarballref(
+                "octocat-rs".to_owned(),
+                "octocat-rs".to_owned(),
+                reference.to_owned(),
+            )
+        };
+
+        let client = MockRequester::new()
+            .with(Interaction::new(endpoint("v0.1.0")).respond_with_text("archive"))
+            .with(Interaction::new(endpoint("v9.9.9")).status(404));
+
+        assert_eq!(
+            download_tarball(&client, "octocat-rs", "octocat-rs", "v0.1.0")
+                .await
+                .unwrap(),
+            b"archive"
+        );
+        assert!(matches!(
+            download_tarball(&client, "octocat-rs", "octocat-rs", "v9.9.9").await,
+            Err(GithubRestError::NotFound(_))
+        ));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::model::{
-    issues::{Issue, Issues},
+    issues::{
+        comments::{IssueComment, IssueComments},
+        nested, Issue, Issues,
+    },
     pull_requests::{PullRequestState, Pulls},
 };
 
@@ -135,6 +138,397 @@ where
         .await
 }
 
+/// Same as [`get_issues`], but returns a stream over every page of results.
+pub fn get_issues_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetIssuesBody>,
+) -> Paginated<'a, Issues>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoIssues(owner.clone(), repo.clone()),
+        options,
+    )
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/issues/{issue_number}`
+/// * docs <https://docs.github.com/rest/reference/issues#get-an-issue>
+///
+/// Get an issue
+/// The API returns a [`301 Moved Permanently` status](https://docs.github.com/rest/overview/resources-in-the-rest-api#http-redirects-redirects) if the issue was
+/// [transferred](https://help.github.com/articles/transferring-an-issue-to-another-repository/) to another repository. If
+/// the issue was transferred to or deleted from a repository where the
+/// authenticated user lacks read access, the API returns a `404 Not Found`
+/// status. If the issue was deleted from a repository where the authenticated
+/// user has read access, the API returns a `410 Gone` status.
+pub async fn get_issue<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+) -> Result<Issue, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Issue>(
+            EndPoints::GetReposownerrepoIssuesissueNumber(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+/// The reason an issue was closed or reopened.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateReason {
+    Completed,
+    NotPlanned,
+    Reopened,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct UpdateIssueBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The open or closed state of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<nested::IssueState>,
+    /// The reason for the state change. Ignored unless `state` is changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_reason: Option<StateReason>,
+    /// The number of the milestone to associate this issue with, or
+    /// `Some(None)` to remove the current milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Option<i64>>,
+    /// Labels to associate with this issue. Pass an empty list to remove all
+    /// labels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// Logins for users to assign to this issue. Pass an empty list to clear
+    /// all assignees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+}
+
+/// * tags issues
+/// * patch `/repos/{owner}/{repo}/issues/{issue_number}`
+/// * docs <https://docs.github.com/rest/reference/issues/#update-an-issue>
+///
+/// Update an issue
+/// Issue owners and users with push access can edit an issue.
+pub async fn update_issue<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &UpdateIssueBody,
+) -> Result<Issue, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Issue>(
+            EndPoints::PatchReposownerrepoIssuesissueNumber(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct LockIssueBody {
+    /// The reason for locking the issue or pull request conversation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_reason: Option<LockReason>,
+}
+
+/// * tags issues
+/// * put `/repos/{owner}/{repo}/issues/{issue_number}/lock`
+/// * docs <https://docs.github.com/rest/reference/issues#lock-an-issue>
+///
+/// Lock an issue
+/// Users with push access can lock an issue or pull request's conversation.
+pub async fn lock_issue<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &LockIssueBody,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::PutReposownerrepoIssuesissueNumberLock(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/issues/{issue_number}/lock`
+/// * docs <https://docs.github.com/rest/reference/issues#unlock-an-issue>
+///
+/// Unlock an issue
+/// Users with push access can unlock an issue's conversation.
+pub async fn unlock_issue<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLock(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct AssigneesBody {
+    /// Usernames of people to assign or unassign. **Note**: Only users with
+    /// push access can add assignees to an issue. Assignees are silently
+    /// ignored otherwise.
+    pub assignees: Vec<String>,
+}
+
+/// * tags issues
+/// * post `/repos/{owner}/{repo}/issues/{issue_number}/assignees`
+/// * docs <https://docs.github.com/rest/reference/issues#add-assignees-to-an-issue>
+///
+/// Add assignees to an issue
+/// Adds up to 10 assignees to an issue. Users already assigned to an issue are
+/// not replaced.
+pub async fn add_assignees<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &AssigneesBody,
+) -> Result<Issue, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Issue>(
+            EndPoints::PostReposownerrepoIssuesissueNumberAssignees(
+                owner.into(),
+                repo.into(),
+                issue_number.to_string(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/issues/{issue_number}/assignees`
+/// * docs <https://docs.github.com/rest/reference/issues#remove-assignees-from-an-issue>
+///
+/// Remove assignees from an issue
+/// Removes one or more assignees from an issue.
+pub async fn remove_assignees<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &AssigneesBody,
+) -> Result<Issue, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Issue>(
+            EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(
+                owner.into(),
+                repo.into(),
+                issue_number.to_string(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct GetIssueCommentsBody {
+    /// Only show notifications updated after the given time. This is a
+    /// timestamp in ISO 8601 format: YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/issues/{issue_number}/comments`
+/// * docs <https://docs.github.com/rest/reference/issues#list-issue-comments>
+///
+/// List issue comments
+/// Issue Comments are ordered by ascending ID.
+pub async fn get_issue_comments<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    options: Option<&GetIssueCommentsBody>,
+) -> Result<IssueComments, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetIssueCommentsBody, String, IssueComments>(
+            EndPoints::GetReposownerrepoIssuesissueNumberComments(owner.into(), repo.into(), issue_number.to_string()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_issue_comments`], but returns a stream over every page of
+/// results.
+pub fn get_issue_comments_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    options: Option<&GetIssueCommentsBody>,
+) -> Paginated<'a, IssueComments>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || {
+            EndPoints::GetReposownerrepoIssuesissueNumberComments(owner.clone(), repo.clone(), issue_number.to_string())
+        },
+        options,
+    )
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct IssueCommentBody {
+    /// **Required**. The contents of the comment.
+    pub body: String,
+}
+
+/// * tags issues
+/// * post `/repos/{owner}/{repo}/issues/{issue_number}/comments`
+/// * docs <https://docs.github.com/rest/reference/issues#create-an-issue-comment>
+///
+/// Create an issue comment
+/// This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+pub async fn create_issue_comment<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &IssueCommentBody,
+) -> Result<IssueComment, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, IssueComment>(
+            EndPoints::PostReposownerrepoIssuesissueNumberComments(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * patch `/repos/{owner}/{repo}/issues/comments/{comment_id}`
+/// * docs <https://docs.github.com/rest/reference/issues#update-an-issue-comment>
+///
+/// Update an issue comment
+pub async fn update_issue_comment<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    comment_id: i64,
+    body: &IssueCommentBody,
+) -> Result<IssueComment, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, IssueComment>(
+            EndPoints::PatchReposownerrepoIssuesCommentscommentId(owner.into(), repo.into(), comment_id.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/issues/comments/{comment_id}`
+/// * docs <https://docs.github.com/rest/reference/issues#delete-an-issue-comment>
+///
+/// Delete an issue comment
+pub async fn delete_issue_comment<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    comment_id: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoIssuesCommentscommentId(owner.into(), repo.into(), comment_id.to_string()),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
 #[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct GetPullsBody {
     /// Either open, closed, or all to filter by state.
@@ -196,9 +590,30 @@ where
         .await
 }
 
+/// Same as [`get_pulls`], but returns a stream over every page of results.
+pub fn get_pulls_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetPullsBody>,
+) -> Paginated<'a, Pulls>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoPulls(owner.clone(), repo.clone()),
+        options,
+    )
+}
+
 #[cfg(feature = "client")]
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
+
     use crate::{client::DefaultRequester, methods::util};
 
     use super::*;
@@ -253,4 +668,84 @@ mod tests {
 
         dbg!(res);
     }
+
+    #[tokio::test]
+    async fn test_get_issues_paginated() {
+        use serde_json::{json, Value};
+
+        use crate::mock::{Interaction, MockRequester};
+
+        let fixture: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/webhooks/issue_comment.json")).unwrap();
+        let issues = |numbers: &[i64]| {
+            let issues = numbers.iter().map(|&number| {
+                let mut issue = fixture["issue"].clone();
+                issue["number"] = json!(number);
+                issue
+            });
+
+            Value::Array(issues.collect())
+        };
+        let endpoint = || EndPoints::GetReposownerrepoIssues("Codertocat".to_owned(), "Hello-World".to_owned());
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(endpoint())
+                    .query(&[("per_page", "2")])
+                    .respond_with(&issues(&[3, 2]))
+                    .header(
+                        "Link",
+                        r#"<https://api.github.com/repositories/186853002/issues?per_page=2&page=2>; rel="next", <https://api.github.com/repositories/186853002/issues?per_page=2&page=2>; rel="last""#,
+                    ),
+            )
+            .with(
+                Interaction::new(endpoint())
+                    .query(&[("per_page", "2"), ("page", "2")])
+                    .respond_with(&issues(&[1])),
+            );
+
+        let body = GetIssuesBody {
+            per_page: Some("2".to_owned()),
+            ..Default::default()
+        };
+
+        let res = get_issues_paginated(&client, "Codertocat", "Hello-World", Some(&body))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(res.iter().map(|issue| issue.number).collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(client.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_lock_issue() {
+        use crate::mock::{Interaction, MockRequester};
+
+        let endpoint = |issue: i64| {
+            EndPoints::PutReposownerrepoIssuesissueNumberLock(
+                "octocat-rs".to_owned(),
+                "octocat-rs".to_owned(),
+                issue.to_string(),
+            )
+        };
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(endpoint(1))
+                    .body(&serde_json::json!({ "lock_reason": "too heated" }))
+                    .status(204),
+            )
+            .with(Interaction::new(endpoint(2)).status(404));
+
+        let body = LockIssueBody {
+            lock_reason: Some(LockReason::TooHeated),
+        };
+
+        lock_issue(&client, "octocat-rs", "octocat-rs", 1, &body).await.unwrap();
+        assert!(matches!(
+            lock_issue(&client, "octocat-rs", "octocat-rs", 2, &body).await,
+            Err(GithubRestError::NotFound(_))
+        ));
+    }
 }
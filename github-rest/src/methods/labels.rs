@@ -0,0 +1,353 @@
+use crate::model::issues::Label;
+
+use super::{prelude::*, util::path_segment, Pagination};
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct LabelsBody {
+    /// The names of the labels to add or set. Labels that don't exist yet
+    /// are created.
+    pub labels: Vec<String>,
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+///
+/// List labels for an issue
+pub async fn get_issue_labels<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    params: Option<&Pagination>,
+) -> Result<Vec<Label>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<Pagination, String, Vec<Label>>(
+            EndPoints::GetReposownerrepoIssuesissueNumberLabels(owner.into(), repo.into(), issue_number.to_string()),
+            params,
+            None,
+        )
+        .await
+}
+
+/// * tags issues
+/// * post `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#add-labels-to-an-issue>
+///
+/// Add labels to an issue
+/// Returns every label of the issue.
+pub async fn add_labels<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &LabelsBody,
+) -> Result<Vec<Label>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Vec<Label>>(
+            EndPoints::PostReposownerrepoIssuesissueNumberLabels(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * put `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#set-labels-for-an-issue>
+///
+/// Set labels for an issue
+/// Removes any previous labels and sets the new labels for an issue.
+pub async fn set_labels<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    body: &LabelsBody,
+) -> Result<Vec<Label>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Vec<Label>>(
+            EndPoints::PutReposownerrepoIssuesissueNumberLabels(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}`
+/// * docs <https://docs.github.com/rest/reference/issues#remove-a-label-from-an-issue>
+///
+/// Remove a label from an issue
+/// Removes the specified label from the issue, and returns the remaining
+/// labels on the issue. This endpoint returns a `404 Not Found` status if the
+/// label does not exist.
+pub async fn remove_label<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+    name: impl Into<String>,
+) -> Result<Vec<Label>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Vec<Label>>(
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+                owner.into(),
+                repo.into(),
+                issue_number.to_string(),
+                path_segment(&name.into()),
+            ),
+            None,
+            None,
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/issues/{issue_number}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#remove-all-labels-from-an-issue>
+///
+/// Remove all labels from an issue
+pub async fn remove_all_labels<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(owner.into(), repo.into(), issue_number.to_string()),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+///
+/// List labels for a repository
+pub async fn get_labels<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    params: Option<&Pagination>,
+) -> Result<Vec<Label>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<Pagination, String, Vec<Label>>(
+            EndPoints::GetReposownerrepoLabels(owner.into(), repo.into()),
+            params,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_labels`], but returns a stream over every page of results.
+pub fn get_labels_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    params: Option<&Pagination>,
+) -> Paginated<'a, Vec<Label>>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoLabels(owner.clone(), repo.clone()),
+        params,
+    )
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/labels/{name}`
+/// * docs <https://docs.github.com/rest/reference/issues#get-a-label>
+///
+/// Get a label
+pub async fn get_label<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+) -> Result<Label, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Label>(
+            EndPoints::GetReposownerrepoLabelsname(owner.into(), repo.into(), path_segment(&name.into())),
+            None,
+            None,
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct CreateLabelBody {
+    /// **Required**. The name of the label. Emoji can be added to label names,
+    /// using either native emoji or colon-style markup.
+    pub name: String,
+    /// The [hexadecimal color code](http://www.color-hex.com/) for the label,
+    /// without the leading `#`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// A short description of the label. Must be 100 characters or fewer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// * tags issues
+/// * post `/repos/{owner}/{repo}/labels`
+/// * docs <https://docs.github.com/rest/reference/issues#create-a-label>
+///
+/// Create a label
+/// Creates a label for the specified repository with the given name and color.
+pub async fn create_label<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    body: &CreateLabelBody,
+) -> Result<Label, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Label>(
+            EndPoints::PostReposownerrepoLabels(owner.into(), repo.into()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct UpdateLabelBody {
+    /// The new name of the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    /// The [hexadecimal color code](http://www.color-hex.com/) for the label,
+    /// without the leading `#`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// A short description of the label. Must be 100 characters or fewer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// * tags issues
+/// * patch `/repos/{owner}/{repo}/labels/{name}`
+/// * docs <https://docs.github.com/rest/reference/issues#update-a-label>
+///
+/// Update a label
+/// Updates a label using the given label name.
+pub async fn update_label<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+    body: &UpdateLabelBody,
+) -> Result<Label, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Label>(
+            EndPoints::PatchReposownerrepoLabelsname(owner.into(), repo.into(), path_segment(&name.into())),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/labels/{name}`
+/// * docs <https://docs.github.com/rest/reference/issues#delete-a-label>
+///
+/// Delete a label
+/// Deletes a label using the given label name.
+pub async fn delete_label<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoLabelsname(owner.into(), repo.into(), path_segment(&name.into())),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::mock::{Interaction, MockRequester};
+
+    #[tokio::test]
+    async fn test_label_name_encoding() {
+        let endpoint = || {
+            EndPoints::DeleteReposownerrepoLabelsname(
+                "octocat-rs".to_owned(),
+                "octocat-rs".to_owned(),
+                "good%20first%2Fissue".to_owned(),
+            )
+        };
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+                    "octocat-rs".to_owned(),
+                    "octocat-rs".to_owned(),
+                    "1".to_owned(),
+                    "good%20first%2Fissue".to_owned(),
+                ))
+                .respond_with(&json!([])),
+            )
+            .with(Interaction::new(endpoint()).status(204));
+
+        assert!(remove_label(&client, "octocat-rs", "octocat-rs", 1, "good first/issue")
+            .await
+            .unwrap()
+            .is_empty());
+        delete_label(&client, "octocat-rs", "octocat-rs", "good first/issue")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.requests()[1].path,
+            "/repos/octocat-rs/octocat-rs/labels/good%20first%2Fissue"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use crate::model::issues::milestones::{Milestone, MilestoneState};
+
+use super::prelude::*;
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct GetMilestonesBody {
+    /// The state of the milestone. Either `open`, `closed`, or `all`.
+    /// Default: open
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// What to sort results by. Either `due_on` or `completeness`.
+    /// Default: due_on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// The direction of the sort. Either `asc` or `desc`.
+    /// Default: asc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    /// Results per page (max 100)
+    /// Default: 30
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<String>,
+    /// Page number of the results to fetch.
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/milestones`
+/// * docs <https://docs.github.com/rest/reference/issues#list-milestones>
+///
+/// List milestones
+/// Lists milestones for a repository.
+pub async fn get_milestones<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetMilestonesBody>,
+) -> Result<Vec<Milestone>, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<GetMilestonesBody, String, Vec<Milestone>>(
+            EndPoints::GetReposownerrepoMilestones(owner.into(), repo.into()),
+            options,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_milestones`], but returns a stream over every page of
+/// results.
+pub fn get_milestones_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    options: Option<&GetMilestonesBody>,
+) -> Paginated<'a, Vec<Milestone>>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoMilestones(owner.clone(), repo.clone()),
+        options,
+    )
+}
+
+/// * tags issues
+/// * get `/repos/{owner}/{repo}/milestones/{milestone_number}`
+/// * docs <https://docs.github.com/rest/reference/issues#get-a-milestone>
+///
+/// Get a milestone
+pub async fn get_milestone<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: i64,
+) -> Result<Milestone, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Milestone>(
+            EndPoints::GetReposownerrepoMilestonesmilestoneNumber(
+                owner.into(),
+                repo.into(),
+                milestone_number.to_string(),
+            ),
+            None,
+            None,
+        )
+        .await
+}
+
+/// Used for creating and updating milestones. `title` is required when
+/// creating a milestone.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct MilestoneBody {
+    /// The title of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The state of the milestone. Default: open
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<MilestoneState>,
+    /// A description of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The milestone due date. This is a timestamp in ISO 8601 format:
+    /// YYYY-MM-DDTHH:MM:SSZ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+}
+
+/// * tags issues
+/// * post `/repos/{owner}/{repo}/milestones`
+/// * docs <https://docs.github.com/rest/reference/issues#create-a-milestone>
+///
+/// Create a milestone
+/// Creates a milestone.
+pub async fn create_milestone<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    body: &MilestoneBody,
+) -> Result<Milestone, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Milestone>(
+            EndPoints::PostReposownerrepoMilestones(owner.into(), repo.into()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * patch `/repos/{owner}/{repo}/milestones/{milestone_number}`
+/// * docs <https://docs.github.com/rest/reference/issues#update-a-milestone>
+///
+/// Update a milestone
+pub async fn update_milestone<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: i64,
+    body: &MilestoneBody,
+) -> Result<Milestone, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, Milestone>(
+            EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(
+                owner.into(),
+                repo.into(),
+                milestone_number.to_string(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags issues
+/// * delete `/repos/{owner}/{repo}/milestones/{milestone_number}`
+/// * docs <https://docs.github.com/rest/reference/issues#delete-a-milestone>
+///
+/// Delete a milestone
+/// Deletes a milestone using the given milestone number.
+pub async fn delete_milestone<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: i64,
+) -> Result<(), GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .raw_req::<String, String>(
+            EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(
+                owner.into(),
+                repo.into(),
+                milestone_number.to_string(),
+            ),
+            None,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
@@ -1,15 +1,29 @@
 //! This module contains helper functions for writing API requests.
 
+pub use actions::*;
+pub use checks::*;
 pub use commits::*;
+pub use contents::*;
 pub use gists::*;
 pub use issues::*;
+pub use labels::*;
+pub use milestones::*;
 pub use misc::*;
+pub use pagination::*;
+pub use pulls::*;
 pub use users::*;
 
+mod actions;
+mod checks;
 mod commits;
+mod contents;
 mod gists;
 mod issues;
+mod labels;
+mod milestones;
 mod misc;
+mod pagination;
+mod pulls;
 mod users;
 pub(crate) mod util;
 
@@ -20,7 +34,7 @@ pub mod prelude {
     pub use reqwest::Body;
     pub use serde::{Deserialize, Serialize};
 
-    pub use crate::{GithubRestError, Requester};
+    pub use crate::{methods::Paginated, GithubRestError, Requester};
 
     pub(crate) use crate::methods::user_and_pagination_methods;
 }
@@ -42,6 +56,16 @@ pub mod prelude {
 ///         .req::<Pagination, String, Type>(EndPoints::Variant(user.into()), params, None)
 ///         .await
 /// }
+///
+/// pub fn name_paginated<'a, T, A>(client: &'a T, user: A, params: Option<&Pagination>) -> Paginated<'a, Type>
+/// where
+///     T: Requester,
+///     A: Into<String>,
+/// {
+///     let user = user.into();
+///
+///     Paginated::new(client, move || EndPoints::Variant(user.clone()), params)
+/// }
 macro_rules! user_and_pagination_methods {
     (
         $(
@@ -65,6 +89,23 @@ macro_rules! user_and_pagination_methods {
                         .req::<Pagination, String, $return_type>($enum_variant(user.into()), params, None)
                         .await
                 }
+
+                $(#[$attr])*
+                ///
+                /// Returns a stream over every page of results.
+                pub fn [<$name _paginated>]<'a, T, A>(
+                    client: &'a T,
+                    user: A,
+                    params: Option<&Pagination>,
+                ) -> crate::methods::Paginated<'a, $return_type>
+                where
+                    T: crate::Requester,
+                    A: Into<String>,
+                {
+                    let user = user.into();
+
+                    crate::methods::Paginated::new(client, move || $enum_variant(user.clone()), params)
+                }
             )*
         }
     }
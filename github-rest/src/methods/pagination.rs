@@ -0,0 +1,179 @@
+use std::{collections::VecDeque, pin::Pin, task};
+
+use futures::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use serde::de::DeserializeOwned;
+
+use super::prelude::*;
+
+/// A [`Stream`] that lazily yields every item of a list endpoint, following
+/// the `Link` header sent by GitHub until there are no pages left.
+///
+/// Pages are only requested once the items of the previous page have been
+/// consumed. If an error is encountered it is yielded and the stream ends.
+///
+/// ```rust,ignore
+/// use futures::TryStreamExt;
+///
+/// let issues = get_issues_paginated(&client, "octocat-rs", "octocat-rs", None)
+///     .max_items(250)
+///     .try_collect::<Vec<_>>()
+///     .await?;
+/// ```
+pub struct Paginated<'a, P>
+where
+    P: IntoIterator,
+{
+    stream: BoxStream<'a, Result<P::Item, GithubRestError>>,
+}
+
+struct State<'a, R, P>
+where
+    P: IntoIterator,
+{
+    client: &'a R,
+    endpoint: Box<dyn Fn() -> EndPoints + Send + Sync + 'a>,
+    /// The query to send with the next request, or `None` if there are no
+    /// pages left.
+    query: Option<Vec<(String, String)>>,
+    buffer: VecDeque<P::Item>,
+}
+
+impl<'a, P> Paginated<'a, P>
+where
+    P: IntoIterator + DeserializeOwned + 'a,
+    P::Item: Send + 'a,
+{
+    /// Creates a new [`Paginated`] stream.
+    ///
+    /// `endpoint` is called once for every page that gets requested, as
+    /// [`EndPoints`] can't be cloned.
+    pub fn new<R, Q>(client: &'a R, endpoint: impl Fn() -> EndPoints + Send + Sync + 'a, query: Option<&Q>) -> Self
+    where
+        R: Requester,
+        Q: Serialize + ?Sized,
+    {
+        let query = match query.map(serde_urlencoded::to_string).transpose() {
+            Ok(query) => query
+                .map(|q| serde_urlencoded::from_str::<Vec<(String, String)>>(&q).unwrap_or_default())
+                .unwrap_or_default(),
+            Err(e) => return Self::error(GithubRestError::UrlEncodeError(e)),
+        };
+
+        let state = State::<'a, R, P> {
+            client,
+            endpoint: Box::new(endpoint),
+            query: Some(query),
+            buffer: VecDeque::new(),
+        };
+
+        Self {
+            stream: Box::pin(stream::unfold(state, State::next)),
+        }
+    }
+
+    /// Creates a [`Paginated`] stream that only yields `error`.
+    fn error(error: GithubRestError) -> Self {
+        Self {
+            stream: Box::pin(stream::once(async { Err(error) })),
+        }
+    }
+
+    /// Limits the amount of items yielded by the stream. No further pages are
+    /// requested once the limit has been reached.
+    pub fn max_items(self, max: usize) -> Self {
+        Self {
+            stream: Box::pin(self.stream.take(max)),
+        }
+    }
+}
+
+impl<'a, R, P> State<'a, R, P>
+where
+    R: Requester,
+    P: IntoIterator + DeserializeOwned,
+{
+    async fn next(mut self) -> Option<(Result<P::Item, GithubRestError>, Self)> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some((Ok(item), self));
+            }
+
+            let query = self.query.take()?;
+
+            let res = match self
+                .client
+                .raw_req_with_meta::<_, String>((self.endpoint)(), Some(&query), None)
+                .await
+            {
+                Ok(res) => res,
+                Err(e) => return Some((Err(e), self)),
+            };
+
+            self.query = res.link().and_then(next_page_query);
+
+            match serde_json::from_str::<P>(&res.body) {
+                Ok(page) => self.buffer.extend(page),
+                Err(e) => {
+                    self.query = None;
+                    return Some((Err(e.into()), self));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, P> Stream for Paginated<'a, P>
+where
+    P: IntoIterator,
+{
+    type Item = Result<P::Item, GithubRestError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> task::Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+/// Finds the URL with `rel="next"` in a `Link` header and returns its query
+/// parameters.
+///
+/// See also: <https://docs.github.com/en/rest/guides/using-pagination-in-the-rest-api#using-link-headers>
+pub(crate) fn next_page_query(link: &str) -> Option<Vec<(String, String)>> {
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+
+        if !params.split(';').any(|p| p.trim() == r#"rel="next""#) {
+            return None;
+        }
+
+        let url = url.trim().trim_start_matches('<').trim_end_matches('>');
+        let (_, query) = url.split_once('?')?;
+
+        serde_urlencoded::from_str(query).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_page_query() {
+        let link = r#"<https://api.github.com/repositories/41881900/issues?per_page=2&since=2022-01-01T00%3A00%3A00Z&page=2>; rel="next", <https://api.github.com/repositories/41881900/issues?per_page=2&page=50>; rel="last""#;
+
+        assert_eq!(
+            next_page_query(link).unwrap(),
+            vec![
+                ("per_page".to_owned(), "2".to_owned()),
+                ("since".to_owned(), "2022-01-01T00:00:00Z".to_owned()),
+                ("page".to_owned(), "2".to_owned()),
+            ]
+        );
+
+        let last = r#"<https://api.github.com/repositories/41881900/issues?page=49>; rel="prev", <https://api.github.com/repositories/41881900/issues?page=1>; rel="first""#;
+
+        assert!(next_page_query(last).is_none());
+    }
+}
@@ -0,0 +1,420 @@
+use crate::model::{
+    commits::Commits,
+    pull_requests::{
+        reviews::PullRequestReview, PullRequest, PullRequestFiles, PullRequestMergeResult, PullRequestState,
+        SimplePullRequest,
+    },
+};
+
+use super::{prelude::*, Pagination};
+
+/// * tags pulls
+/// * get `/repos/{owner}/{repo}/pulls/{pull_number}`
+/// * docs <https://docs.github.com/rest/reference/pulls#get-a-pull-request>
+///
+/// Get a pull request
+/// Lists details of a pull request by providing its number.
+///
+/// The value of the `mergeable` attribute can be `true`, `false`, or `null`.
+/// If the value is `null`, then GitHub has started a background job to compute
+/// the mergeability. After giving the job time to complete, resubmit the
+/// request. When the job finishes, you will see a non-`null` value for the
+/// `mergeable` attribute in the response.
+pub async fn get_pull<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+) -> Result<PullRequest, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, PullRequest>(
+            EndPoints::GetReposownerrepoPullspullNumber(owner.into(), repo.into(), pull_number.to_string()),
+            None,
+            None,
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CreatePullBody {
+    /// The title of the new pull request. Required unless `issue` is
+    /// specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// **Required**. The name of the branch where your changes are
+    /// implemented. For cross-repository pull requests in the same network,
+    /// namespace `head` with a user like this: `username:branch`.
+    pub head: String,
+    /// **Required**. The name of the branch you want the changes pulled into.
+    pub base: String,
+    /// The contents of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Indicates whether maintainers can modify the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+    /// Indicates whether the pull request is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    /// An issue in the repository to convert to a pull request. The issue
+    /// title, body, and comments will become the title, body, and comments on
+    /// the new pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<i64>,
+}
+
+/// * tags pulls
+/// * post `/repos/{owner}/{repo}/pulls`
+/// * docs <https://docs.github.com/rest/reference/pulls#create-a-pull-request>
+///
+/// Create a pull request
+/// Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+///
+/// To open or update a pull request in a public repository, you must have write
+/// access to the head or the source branch. For organization-owned
+/// repositories, you must be a member of the organization that owns the
+/// repository to open or update a pull request.
+pub async fn create_pull<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    body: &CreatePullBody,
+) -> Result<PullRequest, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, PullRequest>(
+            EndPoints::PostReposownerrepoPulls(owner.into(), repo.into()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UpdatePullBody {
+    /// The title of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The contents of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// State of this Pull Request. Either `open` or `closed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<PullRequestState>,
+    /// The name of the branch you want your changes pulled into. This should
+    /// be an existing branch on the current repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// Indicates whether maintainers can modify the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+}
+
+/// * tags pulls
+/// * patch `/repos/{owner}/{repo}/pulls/{pull_number}`
+/// * docs <https://docs.github.com/rest/reference/pulls/#update-a-pull-request>
+///
+/// Update a pull request
+/// Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+///
+/// To open or update a pull request in a public repository, you must have write
+/// access to the head or the source branch. For organization-owned
+/// repositories, you must be a member of the organization that owns the
+/// repository to open or update a pull request.
+pub async fn update_pull<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    body: &UpdatePullBody,
+) -> Result<PullRequest, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, PullRequest>(
+            EndPoints::PatchReposownerrepoPullspullNumber(owner.into(), repo.into(), pull_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    #[default]
+    Merge,
+    Squash,
+    Rebase,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct MergePullBody {
+    /// Title for the automatic commit message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_title: Option<String>,
+    /// Extra detail to append to automatic commit message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_message: Option<String>,
+    /// SHA that pull request head must match to allow merge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    /// Merge method to use. Default: `merge`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_method: Option<MergeMethod>,
+}
+
+/// * tags pulls
+/// * put `/repos/{owner}/{repo}/pulls/{pull_number}/merge`
+/// * docs <https://docs.github.com/rest/reference/pulls#merge-a-pull-request>
+///
+/// Merge a pull request
+/// This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+///
+/// Fails with [`GithubRestError::ResponseError`] (`405`) if the pull request
+/// can't be merged, and with [`GithubRestError::Conflict`] if `sha` doesn't
+/// match the head of the pull request.
+pub async fn merge_pull<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    body: &MergePullBody,
+) -> Result<PullRequestMergeResult, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, PullRequestMergeResult>(
+            EndPoints::PutReposownerrepoPullspullNumberMerge(owner.into(), repo.into(), pull_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+/// * tags pulls
+/// * get `/repos/{owner}/{repo}/pulls/{pull_number}/files`
+/// * docs <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+///
+/// List pull requests files
+/// **Note:** Responses include a maximum of 3000 files. The paginated response
+/// returns 30 files per page by default.
+pub async fn get_pull_files<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    params: Option<&Pagination>,
+) -> Result<PullRequestFiles, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<Pagination, String, PullRequestFiles>(
+            EndPoints::GetReposownerrepoPullspullNumberFiles(owner.into(), repo.into(), pull_number.to_string()),
+            params,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_pull_files`], but returns a stream over every page of
+/// results.
+pub fn get_pull_files_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    params: Option<&Pagination>,
+) -> Paginated<'a, PullRequestFiles>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || EndPoints::GetReposownerrepoPullspullNumberFiles(owner.clone(), repo.clone(), pull_number.to_string()),
+        params,
+    )
+}
+
+/// * tags pulls
+/// * get `/repos/{owner}/{repo}/pulls/{pull_number}/commits`
+/// * docs <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+///
+/// List commits on a pull request
+/// Lists a maximum of 250 commits for a pull request. To receive a complete
+/// commit list for pull requests with more than 250 commits, use the [List
+/// commits](https://docs.github.com/rest/reference/repos#list-commits)
+/// endpoint.
+pub async fn get_pull_commits<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    params: Option<&Pagination>,
+) -> Result<Commits, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<Pagination, String, Commits>(
+            EndPoints::GetReposownerrepoPullspullNumberCommits(owner.into(), repo.into(), pull_number.to_string()),
+            params,
+            None,
+        )
+        .await
+}
+
+/// Same as [`get_pull_commits`], but returns a stream over every page of
+/// results.
+pub fn get_pull_commits_paginated<'a, T>(
+    client: &'a T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    params: Option<&Pagination>,
+) -> Paginated<'a, Commits>
+where
+    T: Requester,
+{
+    let (owner, repo) = (owner.into(), repo.into());
+
+    Paginated::new(
+        client,
+        move || {
+            EndPoints::GetReposownerrepoPullspullNumberCommits(owner.clone(), repo.clone(), pull_number.to_string())
+        },
+        params,
+    )
+}
+
+/// The action to perform when submitting a review. Leaving it blank creates a
+/// `PENDING` review, which can be submitted later.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+/// An inline comment that is part of a review.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ReviewCommentBody {
+    /// **Required**. The relative path to the file that necessitates a review
+    /// comment.
+    pub path: String,
+    /// **Required**. Text of the review comment.
+    pub body: String,
+    /// The line of the blob in the pull request diff that the comment applies
+    /// to. For a multi-line comment, the last line of the range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<i64>,
+    /// `LEFT` for deletions, or `RIGHT` for additions and unchanged lines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    /// The first line of a multi-line comment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<i64>,
+    /// The side of the first line of a multi-line comment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_side: Option<String>,
+    /// **Deprecated**. Use `line` instead. The position in the diff where you
+    /// want to add a review comment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CreateReviewBody {
+    /// The SHA of the commit that needs a review. Default: the most recent
+    /// commit in the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    /// **Required** when using `REQUEST_CHANGES` or `COMMENT` for the `event`
+    /// parameter. The body text of the pull request review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The review action you want to perform. Default: a `PENDING` review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<ReviewEvent>,
+    /// Draft review comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<ReviewCommentBody>>,
+}
+
+/// * tags pulls
+/// * post `/repos/{owner}/{repo}/pulls/{pull_number}/reviews`
+/// * docs <https://docs.github.com/rest/reference/pulls#create-a-review-for-a-pull-request>
+///
+/// Create a review for a pull request
+/// This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+///
+/// Pull request reviews created in the `PENDING` state do not include the
+/// `submitted_at` property in the response.
+pub async fn create_review<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    body: &CreateReviewBody,
+) -> Result<PullRequestReview, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, PullRequestReview>(
+            EndPoints::PostReposownerrepoPullspullNumberReviews(owner.into(), repo.into(), pull_number.to_string()),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RequestReviewersBody {
+    /// An array of user `login`s that will be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<String>>,
+    /// An array of team `slug`s that will be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_reviewers: Option<Vec<String>>,
+}
+
+/// * tags pulls
+/// * post `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers`
+/// * docs <https://docs.github.com/rest/reference/pulls#request-reviewers-for-a-pull-request>
+///
+/// Request reviewers for a pull request
+/// This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+pub async fn request_reviewers<T>(
+    client: &T,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pull_number: i64,
+    body: &RequestReviewersBody,
+) -> Result<SimplePullRequest, GithubRestError>
+where
+    T: Requester,
+{
+    client
+        .req::<String, String, SimplePullRequest>(
+            EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(
+                owner.into(),
+                repo.into(),
+                pull_number.to_string(),
+            ),
+            None,
+            Some(serde_json::to_string(body)?),
+        )
+        .await
+}
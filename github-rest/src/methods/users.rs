@@ -21,6 +21,14 @@ where
         .await
 }
 
+/// Same as [`get_followers`], but returns a stream over every page of results.
+pub fn get_followers_paginated<'a, T>(client: &'a T, params: Option<&Pagination>) -> Paginated<'a, Vec<SimpleUser>>
+where
+    T: Requester,
+{
+    Paginated::new(client, EndPoints::GetUserFollowers, params)
+}
+
 /// * tags users
 /// * get `/user/following`
 /// * docs <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
@@ -36,6 +44,14 @@ where
         .await
 }
 
+/// Same as [`get_following`], but returns a stream over every page of results.
+pub fn get_following_paginated<'a, T>(client: &'a T, params: Option<&Pagination>) -> Paginated<'a, Vec<SimpleUser>>
+where
+    T: Requester,
+{
+    Paginated::new(client, EndPoints::GetUserFollowing, params)
+}
+
 user_and_pagination_methods!(
     /// * tags users
     /// * get `/users/{username}/keys`
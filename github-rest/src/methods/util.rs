@@ -78,3 +78,14 @@ pub fn path_and_position(
 
     builder
 }
+
+/// Percent-encodes `s` so that it can be used as a single path segment, e.g.
+/// for label names containing a `/` or spaces.
+pub fn path_segment(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
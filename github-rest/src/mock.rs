@@ -0,0 +1,598 @@
+//! This module contains [`MockRequester`], an implementation of the
+//! [`Requester`] trait that answers requests with canned responses instead of
+//! calling the API, and [`RecordingRequester`], used to record those
+//! responses from the real API into cassette files.
+//!
+//! ```rust,ignore
+//! let client = MockRequester::new().with(
+//!     Interaction::new(EndPoints::GetReposownerrepoIssues("octocat-rs".to_owned(), "octocat-rs".to_owned()))
+//!         .query(&[("state", "open")])
+//!         .respond_with(&serde_json::json!([])),
+//! );
+//!
+//! let issues = get_issues(&client, "octocat-rs", "octocat-rs", Some(&params)).await?;
+//! ```
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use github_api_octocat::end_points::{EndPoints, Methods};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{ApiError, GithubRestError, Requester, Response};
+
+/// A request as seen by [`MockRequester`] or [`RecordingRequester`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    /// The URL encoded query string. Recorded requests without a query have
+    /// an empty query string, while `None` matches any query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// The request body. Bodies that aren't valid JSON are stored as a JSON
+    /// string. `None` matches any body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A canned response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+/// A request along with the response it should be answered with. Cassettes
+/// are JSON arrays of [`Interaction`]s.
+///
+/// When used with [`MockRequester`], requests match an [`Interaction`] if the
+/// method and path are the same. The query and body are only compared if they
+/// have been set.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+impl Interaction {
+    /// Creates an [`Interaction`] that matches any request to `endpoint`, and
+    /// responds with `200 OK` and an empty JSON object.
+    pub fn new(endpoint: EndPoints) -> Self {
+        Self::path(endpoint.method(), &endpoint.path())
+    }
+
+    /// Creates an [`Interaction`] that matches any `method` request to
+    /// `path`, for requests sent using [`Requester::raw_req_path`].
+    pub fn path(method: Methods, path: &str) -> Self {
+        Self {
+            request: RecordedRequest {
+                method: method_name(&method).to_owned(),
+                path: path.to_owned(),
+                query: None,
+                body: None,
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: HashMap::new(),
+                body: "{}".to_owned(),
+            },
+        }
+    }
+
+    /// Only match requests with this query.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.request.query = Some(serde_urlencoded::to_string(query).expect("Interaction: Serializing query"));
+        self
+    }
+
+    /// Only match requests with this JSON body.
+    pub fn body<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.request.body = Some(serde_json::to_value(body).expect("Interaction: Serializing body"));
+        self
+    }
+
+    /// Sets the JSON body of the response.
+    pub fn respond_with<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.response.body = serde_json::to_string(body).expect("Interaction: Serializing response");
+        self
+    }
+
+    /// Sets the body of the response, for endpoints that don't return JSON.
+    pub fn respond_with_text<V: Into<String>>(mut self, body: V) -> Self {
+        self.response.body = body.into();
+        self
+    }
+
+    /// Sets the status code of the response.
+    pub fn status(mut self, status: u16) -> Self {
+        self.response.status = status;
+        self
+    }
+
+    /// Adds a header to the response.
+    pub fn header<K: AsRef<str>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.response.headers.insert(name.as_ref().to_lowercase(), value.into());
+        self
+    }
+
+    fn matches(&self, req: &RecordedRequest) -> bool {
+        self.request.method == req.method
+            && self.request.path == req.path
+            && (self.request.query.is_none() || self.request.query == req.query)
+            && (self.request.body.is_none() || self.request.body == req.body)
+    }
+}
+
+/// A [`Requester`] that answers requests using a list of [`Interaction`]s.
+///
+/// If several interactions match a request, they are used in order, with the
+/// last one being repeated once all of them have been used. Requests that
+/// don't match anything fail with [`GithubRestError::NotFound`].
+#[derive(Debug, Default)]
+pub struct MockRequester {
+    interactions: Vec<Interaction>,
+    uses: Mutex<Vec<usize>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockRequester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`MockRequester`] that replays the given interactions.
+    pub fn from_interactions(interactions: Vec<Interaction>) -> Self {
+        Self {
+            uses: Mutex::new(vec![0; interactions.len()]),
+            interactions,
+            requests: Mutex::default(),
+        }
+    }
+
+    /// Creates a [`MockRequester`] that replays a cassette written by
+    /// [`RecordingRequester::save`].
+    #[cfg(not(target_family = "wasm"))]
+    pub fn from_cassette<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let cassette = std::fs::read_to_string(path)?;
+
+        Ok(Self::from_interactions(serde_json::from_str(&cassette)?))
+    }
+
+    /// Adds an [`Interaction`] to the current requester.
+    pub fn with(mut self, interaction: Interaction) -> Self {
+        self.interactions.push(interaction);
+        self.uses.get_mut().unwrap().push(0);
+        self
+    }
+
+    /// Gets every request that has been received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, req: RecordedRequest) -> Result<Response<String>, GithubRestError> {
+        self.requests.lock().unwrap().push(req.clone());
+
+        let mut uses = self.uses.lock().unwrap();
+
+        let matching = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.matches(&req))
+            .map(|(n, _)| n)
+            .collect::<Vec<_>>();
+
+        let n = match matching.iter().find(|n| uses[**n] == 0).or(matching.last()) {
+            Some(n) => *n,
+            None => {
+                return Err(GithubRestError::NotFound(ApiError {
+                    message: format!("no interaction matches {} {}", req.method, req.path),
+                    ..ApiError::default()
+                }))
+            }
+        };
+
+        uses[n] += 1;
+
+        let response = &self.interactions[n].response;
+
+        Response {
+            body: response.body.clone(),
+            status: response.status,
+            headers: response.headers.clone(),
+        }
+        .error_for_status()
+    }
+}
+
+#[async_trait]
+impl Requester for MockRequester {
+    type Body = String;
+
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        Ok(self.raw_req_with_meta(url, query, body).await?.body)
+    }
+
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        self.respond(RecordedRequest::new(
+            &url.method(),
+            &url.path(),
+            query,
+            body.map(Into::into),
+        )?)
+    }
+
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+    {
+        let res = self.respond(RecordedRequest::new(&url.method(), &url.path(), query, None)?)?;
+
+        Ok(res.map(String::into_bytes))
+    }
+
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        self.respond(RecordedRequest::new(&method, path, query, body.map(Into::into))?)
+    }
+
+    async fn req<T, V, A: DeserializeOwned>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<A, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let r = self.raw_req(url, query, body).await?;
+        Ok(serde_json::from_str(&r)?)
+    }
+}
+
+/// Wraps a [`Requester`], recording every request and response so that they
+/// can be saved as a cassette and replayed using [`MockRequester`].
+///
+/// Make sure to remove any secrets from the recorded responses before
+/// committing cassettes.
+pub struct RecordingRequester<R> {
+    inner: R,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<R> RecordingRequester<R>
+where
+    R: Requester,
+{
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            interactions: Mutex::default(),
+        }
+    }
+
+    /// Gets every interaction that has been recorded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    fn record(
+        &self,
+        request: RecordedRequest,
+        res: Result<Response<String>, GithubRestError>,
+    ) -> Result<Response<String>, GithubRestError> {
+        let (status, headers, response_body) = match res {
+            Ok(res) => (res.status, res.headers, res.body),
+            Err(e) => match error_response(&e) {
+                Some(response) => response,
+                None => return Err(e),
+            },
+        };
+
+        self.interactions.lock().unwrap().push(Interaction {
+            request,
+            response: RecordedResponse {
+                status,
+                headers: headers.clone(),
+                body: response_body.clone(),
+            },
+        });
+
+        Response {
+            body: response_body,
+            status,
+            headers,
+        }
+        .error_for_status()
+    }
+
+    /// Writes the recorded interactions to a cassette file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        let cassette = serde_json::to_string_pretty(&*self.interactions.lock().unwrap())?;
+
+        std::fs::write(path, cassette)
+    }
+}
+
+#[async_trait]
+impl<R> Requester for RecordingRequester<R>
+where
+    R: Requester,
+{
+    type Body = String;
+
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        Ok(self.raw_req_with_meta(url, query, body).await?.body)
+    }
+
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let body = body.map(Into::into);
+        let request = RecordedRequest::new(&url.method(), &url.path(), query, body.clone())?;
+
+        self.record(request, self.inner.raw_req_with_meta(url, query, body).await)
+    }
+
+    /// Binary bodies are recorded lossily, as cassettes store bodies as
+    /// text.
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+    {
+        let request = RecordedRequest::new(&url.method(), &url.path(), query, None)?;
+
+        let (status, headers, response_body) = match self.inner.raw_req_bytes(url, query).await {
+            Ok(res) => (res.status, res.headers, res.body),
+            Err(e) => match error_response(&e) {
+                Some((status, headers, body)) => (status, headers, body.into_bytes()),
+                None => return Err(e),
+            },
+        };
+
+        self.interactions.lock().unwrap().push(Interaction {
+            request,
+            response: RecordedResponse {
+                status,
+                headers: headers.clone(),
+                body: String::from_utf8_lossy(&response_body).into_owned(),
+            },
+        });
+
+        Response {
+            body: response_body,
+            status,
+            headers,
+        }
+        .error_for_status()
+    }
+
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let body = body.map(Into::into);
+        let request = RecordedRequest::new(&method, path, query, body.clone())?;
+
+        self.record(request, self.inner.raw_req_path(method, path, query, body).await)
+    }
+
+    async fn req<T, V, A: DeserializeOwned>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<A, GithubRestError>
+    where
+        T: Serialize + ?Sized + std::marker::Send + std::marker::Sync,
+        V: Into<Self::Body> + std::marker::Send,
+    {
+        let r = self.raw_req(url, query, body).await?;
+        Ok(serde_json::from_str(&r)?)
+    }
+}
+
+impl RecordedRequest {
+    fn new<T>(method: &Methods, path: &str, query: Option<&T>, body: Option<String>) -> Result<Self, GithubRestError>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(Self {
+            method: method_name(method).to_owned(),
+            path: path.to_owned(),
+            query: Some(query.map(serde_urlencoded::to_string).transpose()?.unwrap_or_default()),
+            body: body.map(|b| serde_json::from_str(&b).unwrap_or(Value::String(b))),
+        })
+    }
+}
+
+/// Turns an API error back into the response it was created from, so that
+/// error responses can be recorded as well. Headers are not preserved.
+fn error_response(e: &GithubRestError) -> Option<(u16, HashMap<String, String>, String)> {
+    let (status, error) = match e {
+        GithubRestError::NotAuthorized(error) => (401, error),
+        GithubRestError::Forbidden(error) => (403, error),
+        GithubRestError::NotFound(error) => (404, error),
+        GithubRestError::Conflict(error) => (409, error),
+        GithubRestError::Gone(error) => (410, error),
+        GithubRestError::Validation(error) => (422, error),
+        GithubRestError::ResponseError(status, error) => (*status, error),
+        _ => return None,
+    };
+
+    Some((status, HashMap::new(), serde_json::to_string(error).ok()?))
+}
+
+fn method_name(method: &Methods) -> &'static str {
+    match method {
+        Methods::Get => "GET",
+        Methods::Post => "POST",
+        Methods::Put => "PUT",
+        Methods::Patch => "PATCH",
+        Methods::Delete => "DELETE",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::methods::{api_info, create_issue, zen, CreateIssueBody};
+
+    #[tokio::test]
+    async fn test_mock_requester() {
+        let client = MockRequester::new()
+            .with(Interaction::new(EndPoints::Get()).respond_with(&json!({ "current_user_url": "/user" })))
+            .with(Interaction::new(EndPoints::GetZen()).respond_with_text("Keep it logically awesome."))
+            .with(Interaction::new(EndPoints::GetZen()).status(404));
+
+        assert_eq!(api_info(&client).await.unwrap(), json!({ "current_user_url": "/user" }));
+
+        // Interactions are used in order, the last one is repeated
+        assert_eq!(zen(&client).await.unwrap(), "Keep it logically awesome.");
+        assert!(matches!(zen(&client).await, Err(GithubRestError::NotFound(_))));
+        assert!(matches!(zen(&client).await, Err(GithubRestError::NotFound(_))));
+
+        assert!(matches!(
+            client.raw_req::<(), String>(EndPoints::GetOctocat(), None, None).await,
+            Err(GithubRestError::NotFound(ApiError { message, .. })) if message == "no interaction matches GET /octocat"
+        ));
+        assert_eq!(client.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_mock_body() {
+        let endpoint = || EndPoints::PostReposownerrepoIssues("octocat-rs".to_owned(), "octocat-rs".to_owned());
+        let body = |title: &str| CreateIssueBody {
+            title: title.to_owned(),
+            body: None,
+            assignee: None,
+            milestone: None,
+            labels: None,
+            assignees: None,
+        };
+
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(endpoint())
+                    .body(&json!({ "title": "Other" }))
+                    .status(422),
+            )
+            .with(
+                Interaction::new(endpoint())
+                    .body(&json!({ "title": "Test" }))
+                    .respond_with(&json!({ "number": 1 }))
+                    .status(201),
+            );
+
+        let res = client
+            .raw_req::<(), _>(endpoint(), None, Some(serde_json::to_string(&body("Test")).unwrap()))
+            .await
+            .unwrap();
+
+        assert_eq!(res, r#"{"number":1}"#);
+        assert_eq!(client.requests()[0].body, Some(json!({ "title": "Test" })));
+        assert!(matches!(
+            create_issue(&client, "octocat-rs", "octocat-rs", &body("Other")).await,
+            Err(GithubRestError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_record_replay() {
+        let endpoint = || EndPoints::GetReposownerrepoIssues("octocat-rs".to_owned(), "octocat-rs".to_owned());
+
+        let real = MockRequester::new()
+            .with(
+                Interaction::new(endpoint())
+                    .query(&[("page", "2")])
+                    .respond_with(&json!([]))
+                    .header("ETag", "\"abc\""),
+            )
+            .with(Interaction::new(endpoint()).respond_with(&json!([{ "number": 1 }])));
+
+        let recorder = RecordingRequester::new(real);
+
+        for query in [None, Some(&[("page", "2")])] {
+            recorder.raw_req::<_, String>(endpoint(), query, None).await.unwrap();
+        }
+
+        let path = std::env::temp_dir().join(format!("octocat-rs-cassette-{}.json", std::process::id()));
+        recorder.save(&path).unwrap();
+
+        let replay = MockRequester::from_cassette(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let res = replay
+            .raw_req_with_meta::<_, String>(endpoint(), Some(&[("page", "2")]), None)
+            .await
+            .unwrap();
+
+        assert_eq!(res.body, "[]");
+        assert_eq!(res.etag(), Some("\"abc\""));
+        assert_eq!(replay.interactions, recorder.interactions());
+    }
+
+    #[tokio::test]
+    async fn test_record_bytes() {
+        let recorder = RecordingRequester::new(
+            MockRequester::new().with(Interaction::new(EndPoints::GetOctocat()).respond_with_text("octocat")),
+        );
+
+        let res = recorder
+            .raw_req_bytes::<String>(EndPoints::GetOctocat(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(res.body, b"octocat");
+        assert_eq!(recorder.interactions()[0].response.body, "octocat");
+    }
+}
@@ -0,0 +1,241 @@
+use crate::model::{prelude::*, repositories::workflows::Workflow, user::GitUser};
+
+/// Implements [`IntoIterator`] for a list response, so that it can be used
+/// with [`Paginated`](crate::methods::Paginated).
+macro_rules! list_response {
+    ($name:ident, $field:ident, $item:ty) => {
+        impl IntoIterator for $name {
+            type Item = $item;
+            type IntoIter = std::vec::IntoIter<$item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.$field.into_iter()
+            }
+        }
+    };
+}
+
+/// <https://docs.github.com/en/rest/actions/workflows#list-repository-workflows>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workflows {
+    pub total_count: i64,
+    pub workflows: Vec<Workflow>,
+}
+
+list_response!(Workflows, workflows, Workflow);
+
+/// A workflow run, as returned by the REST API.
+///
+/// <https://docs.github.com/en/rest/actions/workflow-runs#get-a-workflow-run>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: i64,
+    pub name: Option<String>,
+    pub node_id: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub path: Option<String>,
+    pub display_title: Option<String>,
+    pub run_number: i64,
+    pub run_attempt: Option<i64>,
+    /// The event that triggered the run, e.g. `push` or `workflow_dispatch`.
+    pub event: String,
+    pub status: Option<WorkflowRunStatus>,
+    /// Only set once the run is completed.
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub workflow_id: i64,
+    pub check_suite_id: Option<i64>,
+    pub url: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub run_started_at: Option<String>,
+    pub jobs_url: String,
+    pub logs_url: String,
+    pub check_suite_url: String,
+    pub artifacts_url: String,
+    pub cancel_url: String,
+    pub rerun_url: String,
+    pub workflow_url: String,
+    pub head_commit: Option<SimpleCommit>,
+}
+
+impl WorkflowRun {
+    /// Whether the run has finished, successfully or not.
+    pub fn is_completed(&self) -> bool {
+        self.status == Some(WorkflowRunStatus::Completed)
+    }
+}
+
+/// The current status of a workflow run.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WorkflowRunStatus {
+    Requested,
+    #[default]
+    Queued,
+    Pending,
+    Waiting,
+    InProgress,
+    Completed,
+}
+
+/// The final conclusion of a completed workflow run, job or job step.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WorkflowRunConclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    Stale,
+    TimedOut,
+    /// The workflow file was invalid, so no jobs were run.
+    StartupFailure,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimpleCommit {
+    pub id: String,
+    pub tree_id: String,
+    pub message: String,
+    pub timestamp: String,
+    pub author: Option<GitUser>,
+    pub committer: Option<GitUser>,
+}
+
+/// <https://docs.github.com/en/rest/actions/workflow-runs#list-workflow-runs-for-a-repository>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRuns {
+    pub total_count: i64,
+    pub workflow_runs: Vec<WorkflowRun>,
+}
+
+list_response!(WorkflowRuns, workflow_runs, WorkflowRun);
+
+/// A job of a workflow run.
+///
+/// <https://docs.github.com/en/rest/actions/workflow-jobs#get-a-job-for-a-workflow-run>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub id: i64,
+    pub run_id: i64,
+    pub run_url: String,
+    pub run_attempt: Option<i64>,
+    pub node_id: String,
+    pub head_sha: String,
+    pub url: String,
+    pub html_url: Option<String>,
+    pub status: JobStatus,
+    /// Only set once the job is completed.
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub steps: Vec<JobStep>,
+    pub check_run_url: String,
+    pub labels: Vec<String>,
+    pub runner_id: Option<i64>,
+    pub runner_name: Option<String>,
+    pub runner_group_id: Option<i64>,
+    pub runner_group_name: Option<String>,
+}
+
+/// The current status of a job or job step.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JobStatus {
+    #[default]
+    Queued,
+    InProgress,
+    Completed,
+    Waiting,
+    Requested,
+    Pending,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobStep {
+    pub name: String,
+    pub status: JobStatus,
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub number: i64,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+/// <https://docs.github.com/en/rest/actions/workflow-jobs#list-jobs-for-a-workflow-run>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Jobs {
+    pub total_count: i64,
+    pub jobs: Vec<Job>,
+}
+
+list_response!(Jobs, jobs, Job);
+
+/// A file uploaded by a workflow run.
+///
+/// <https://docs.github.com/en/rest/actions/artifacts#get-an-artifact>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub id: i64,
+    pub node_id: String,
+    pub name: String,
+    pub size_in_bytes: i64,
+    pub url: String,
+    pub archive_download_url: String,
+    pub expired: bool,
+    pub created_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub workflow_run: Option<ArtifactWorkflowRun>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArtifactWorkflowRun {
+    pub id: i64,
+    pub repository_id: i64,
+    pub head_repository_id: i64,
+    pub head_branch: String,
+    pub head_sha: String,
+}
+
+/// <https://docs.github.com/en/rest/actions/artifacts#list-artifacts-for-a-repository>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifacts {
+    pub total_count: i64,
+    pub artifacts: Vec<Artifact>,
+}
+
+list_response!(Artifacts, artifacts, Artifact);
+
+/// A cache created by the `actions/cache` action.
+///
+/// <https://docs.github.com/en/rest/actions/cache#list-github-actions-caches-for-a-repository>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionsCache {
+    pub id: i64,
+    /// The git ref the cache was created for, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub key: String,
+    pub version: String,
+    pub last_accessed_at: String,
+    pub created_at: String,
+    pub size_in_bytes: i64,
+}
+
+/// <https://docs.github.com/en/rest/actions/cache#list-github-actions-caches-for-a-repository>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionsCaches {
+    pub total_count: i64,
+    pub actions_caches: Vec<ActionsCache>,
+}
+
+list_response!(ActionsCaches, actions_caches, ActionsCache);
@@ -0,0 +1,53 @@
+use crate::model::{prelude::*, repositories::workflows::events::nested::CheckRun};
+
+/// The current status of a check run.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckRunStatus {
+    #[default]
+    Queued,
+    InProgress,
+    Completed,
+    /// Only GitHub Actions can set this status.
+    Waiting,
+    /// Only GitHub Actions can set this status.
+    Requested,
+    /// Only GitHub Actions can set this status.
+    Pending,
+}
+
+/// The final conclusion of a check run. Setting a conclusion automatically
+/// sets the status of the check run to [`CheckRunStatus::Completed`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckRunConclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    /// Only GitHub can set this conclusion.
+    Stale,
+    TimedOut,
+}
+
+/// The severity of a check run annotation.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnnotationLevel {
+    #[default]
+    Notice,
+    Warning,
+    Failure,
+}
+
+/// <https://docs.github.com/en/rest/checks/runs#list-check-runs-for-a-git-reference>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRuns {
+    pub total_count: i64,
+    pub check_runs: Vec<CheckRun>,
+}
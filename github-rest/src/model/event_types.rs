@@ -1,10 +1,55 @@
-use crate::model::{organizations::SimpleOrganization, prelude::*, repositories::Repository, user::SimpleUser};
+use crate::model::{
+    apps::events::{
+        AppAuthorizationEvent, InstallationAction, InstallationEvent, InstallationRepositoriesAction,
+        InstallationRepositoriesEvent,
+    },
+    commits::events::{CommitCommentAction, CommitCommentEvent, StatusEvent},
+    discussions::events::{DiscussionAction, DiscussionCommentAction, DiscussionCommentEvent, DiscussionEvent},
+    issues::events::{IssueAction, IssueCommentAction, IssueCommentEvent, IssueEvent, LabelAction, LabelEvent},
+    misc::events::{
+        DeploymentAction, DeploymentEvent, DeploymentStatusAction, DeploymentStatusEvent, MarketplacePurchaseAction,
+        MarketplacePurchaseEvent, MetaAction, MetaEvent, SponsorshipAction, SponsorshipEvent,
+    },
+    organizations::{
+        events::{
+            MembershipAction, MembershipEvent, OrgBlockAction, OrgBlockEvent, OrganizationAction, OrganizationEvent,
+            TeamAction, TeamEvent,
+        },
+        SimpleOrganization,
+    },
+    prelude::*,
+    pull_requests::events::{
+        PullRequestAction, PullRequestEvent, PullRequestReviewAction, PullRequestReviewCommentEvent,
+        PullRequestReviewEvent,
+    },
+    releases::events::{CreateEvent, DeleteEvent, ReleaseAction, ReleaseEvent},
+    repositories::{
+        events::{
+            BranchProtectionRuleAction, BranchProtectionRuleEvent, CodeScanningAlertAction, CodeScanningAlertEvent,
+            DeployKeyAction, DeployKeyEvent, ForkEvent, MemberAction, MemberEvent, MilestoneAction, MilestoneEvent,
+            PackageAction, PackageEvent, PingEvent, ProjectAction, ProjectCardAction, ProjectCardEvent,
+            ProjectColumnAction, ProjectColumnEvent, ProjectEvent, PublicEvent, PushEvent, RepositoryAction,
+            RepositoryDispatchEvent, RepositoryEvent, RepositoryImportAction, RepositoryImportEvent,
+            RepositoryVulnerabilityAlertAction, RepositoryVulnerabilityAlertEvent, SecretScanningAlertAction,
+            SecretScanningAlertEvent, StarAction, StarEvent, TeamAddEvent, WatchAction, WatchEvent,
+        },
+        security_advisory::events::{SecurityAdvisoryAction, SecurityAdvisoryEvent},
+        wiki::events::GollumEvent,
+        workflows::events::{
+            nested::{CheckRunAction, WorkflowJobAction, WorkflowRunAction},
+            CheckRunEvent, CheckSuiteAction, CheckSuiteEvent, PageBuildEvent, WorkflowDispatchEvent, WorkflowJobEvent,
+            WorkflowRunEvent,
+        },
+        Repository,
+    },
+    user::SimpleUser,
+};
 
 /// Used to represent all possible values for the `x-github-event` header sent
 /// with all webhook payloads.
 ///
 /// See also: <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads>
-#[derive(Deserialize, EnumString, EnumVariantNames, Debug)]
+#[derive(Deserialize, EnumString, EnumVariantNames, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum EventTypes {
@@ -90,6 +135,140 @@ pub enum EventTypes {
     Sponsorship,
 }
 
+macro_rules! webhook_event {
+    ($($variant:ident($ty:ty $(, $action:ty)?)),* $(,)?) => {
+        /// A webhook payload of any of the types listed in [`EventTypes`].
+        ///
+        /// ```rust,ignore
+        /// let event = WebhookEvent::parse(EventTypes::Push, body)?;
+        ///
+        /// if let WebhookEvent::Push(push) = event {
+        ///     println!("{} commits pushed", push.commits.len());
+        /// }
+        /// ```
+        #[derive(Debug, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum WebhookEvent {
+            $($variant($ty),)*
+        }
+
+        impl WebhookEvent {
+            /// Parses the payload of a webhook delivery, using the event type
+            /// sent in its `X-GitHub-Event` header.
+            pub fn parse(event_type: EventTypes, body: &[u8]) -> Result<Self, serde_json::Error> {
+                Ok(match event_type {
+                    $(EventTypes::$variant => Self::$variant(serde_json::from_slice(body)?),)*
+                })
+            }
+
+            /// Gets the type of the current event.
+            pub fn event_type(&self) -> EventTypes {
+                match self {
+                    $(Self::$variant(_) => EventTypes::$variant,)*
+                }
+            }
+        }
+
+        $(
+            impl WebhookPayload for $ty {
+                type Action = webhook_event!(@action $($action)?);
+
+                const EVENT_TYPE: EventTypes = EventTypes::$variant;
+
+                fn from_webhook_event(event: &WebhookEvent) -> Option<&Self> {
+                    match event {
+                        WebhookEvent::$variant(payload) => Some(payload),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+
+                fn action(&self) -> Option<&Self::Action> {
+                    webhook_event!(@get_action self $($action)?)
+                }
+            }
+        )*
+    };
+    (@action) => { std::convert::Infallible };
+    (@action $action:ty) => { $action };
+    (@get_action $self:ident) => { None };
+    (@get_action $self:ident $action:ty) => { Some(&$self.action) };
+}
+
+/// Implemented by every payload type wrapped by [`WebhookEvent`].
+pub trait WebhookPayload: Sized {
+    /// The type of the `action` field of the payload. Events without an
+    /// action use [`Infallible`](std::convert::Infallible).
+    type Action: std::fmt::Debug + PartialEq;
+
+    /// The type of event the payload belongs to.
+    const EVENT_TYPE: EventTypes;
+
+    /// Gets the payload out of a [`WebhookEvent`] if it is of the current
+    /// type.
+    fn from_webhook_event(event: &WebhookEvent) -> Option<&Self>;
+
+    /// Gets the action that triggered the event, if the payload has one.
+    fn action(&self) -> Option<&Self::Action>;
+}
+
+webhook_event! {
+    Push(PushEvent),
+    GithubAppAuthorization(AppAuthorizationEvent, String),
+    Installation(InstallationEvent, InstallationAction),
+    InstallationRepositories(InstallationRepositoriesEvent, InstallationRepositoriesAction),
+    DeployKey(DeployKeyEvent, DeployKeyAction),
+    Gollum(GollumEvent),
+    Member(MemberEvent, MemberAction),
+    Milestone(MilestoneEvent, MilestoneAction),
+    Public(PublicEvent),
+    Release(ReleaseEvent, ReleaseAction),
+    Repository(RepositoryEvent, RepositoryAction),
+    RepositoryDispatch(RepositoryDispatchEvent, String),
+    RepositoryImport(RepositoryImportEvent, RepositoryImportAction),
+    RepositoryVulnerabilityAlert(RepositoryVulnerabilityAlertEvent, RepositoryVulnerabilityAlertAction),
+    SecretScanningAlert(SecretScanningAlertEvent, SecretScanningAlertAction),
+    SecurityAdvisory(SecurityAdvisoryEvent, SecurityAdvisoryAction),
+    Star(StarEvent, StarAction),
+    Watch(WatchEvent, WatchAction),
+    PullRequest(PullRequestEvent, PullRequestAction),
+    PullRequestReview(PullRequestReviewEvent, PullRequestReviewAction),
+    PullRequestReviewComment(PullRequestReviewCommentEvent, IssueCommentAction),
+    CommitComment(CommitCommentEvent, CommitCommentAction),
+    Status(StatusEvent),
+    IssueComment(IssueCommentEvent, IssueCommentAction),
+    Issues(IssueEvent, IssueAction),
+    Label(LabelEvent, LabelAction),
+    Discussion(DiscussionEvent, DiscussionAction),
+    DiscussionComment(DiscussionCommentEvent, DiscussionCommentAction),
+    BranchProtectionRule(BranchProtectionRuleEvent, BranchProtectionRuleAction),
+    Create(CreateEvent),
+    Delete(DeleteEvent),
+    Fork(ForkEvent),
+    CheckRun(CheckRunEvent, CheckRunAction),
+    CheckSuite(CheckSuiteEvent, CheckSuiteAction),
+    CodeScanningAlert(CodeScanningAlertEvent, CodeScanningAlertAction),
+    Deployment(DeploymentEvent, DeploymentAction),
+    DeploymentStatus(DeploymentStatusEvent, DeploymentStatusAction),
+    PageBuild(PageBuildEvent),
+    WorkflowDispatch(WorkflowDispatchEvent),
+    WorkflowJob(WorkflowJobEvent, WorkflowJobAction),
+    WorkflowRun(WorkflowRunEvent, WorkflowRunAction),
+    Membership(MembershipEvent, MembershipAction),
+    OrgBlock(OrgBlockEvent, OrgBlockAction),
+    Organization(OrganizationEvent, OrganizationAction),
+    Team(TeamEvent, TeamAction),
+    TeamAdd(TeamAddEvent),
+    Project(ProjectEvent, ProjectAction),
+    ProjectCard(ProjectCardEvent, ProjectCardAction),
+    ProjectColumn(ProjectColumnEvent, ProjectColumnAction),
+    MarketplacePurchase(MarketplacePurchaseEvent, MarketplacePurchaseAction),
+    Meta(MetaEvent, MetaAction),
+    Package(PackageEvent, PackageAction),
+    Ping(PingEvent),
+    Sponsorship(SponsorshipEvent, SponsorshipAction),
+}
+
 /// Used to represent the base fields provided by events originating from
 /// repositories.
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
@@ -145,3 +324,51 @@ pub(crate) mod macros {
     pub(crate) use org_origin;
     pub(crate) use repo_origin;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_webhook_event() {
+        let ping = PingEvent {
+            zen: "Design for failure.".to_owned(),
+            hook_id: 1,
+            hook: Value::Null,
+            event_info: RepoEventInfo::default(),
+        };
+        let body = serde_json::to_vec(&ping).unwrap();
+        let event = WebhookEvent::parse(EventTypes::Ping, &body);
+
+        assert!(matches!(event, Ok(WebhookEvent::Ping(_))), "{event:?}");
+        let event = event.unwrap();
+        assert_eq!(event.event_type(), EventTypes::Ping);
+        assert_eq!(PingEvent::from_webhook_event(&event).map(|ping| ping.hook_id), Some(1));
+        assert!(PushEvent::from_webhook_event(&event).is_none());
+
+        assert!(WebhookEvent::parse(EventTypes::Push, b"{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_review_and_comment_actions() {
+        let event = WebhookEvent::parse(
+            EventTypes::PullRequestReview,
+            include_bytes!("../../tests/fixtures/webhooks/pull_request_review.json"),
+        )
+        .unwrap();
+        let review = PullRequestReviewEvent::from_webhook_event(&event).unwrap();
+
+        assert_eq!(review.action(), Some(&PullRequestReviewAction::Submitted));
+        assert_eq!(review.pull_request.number, 2);
+
+        let event = WebhookEvent::parse(
+            EventTypes::IssueComment,
+            include_bytes!("../../tests/fixtures/webhooks/issue_comment.json"),
+        )
+        .unwrap();
+        let comment = IssueCommentEvent::from_webhook_event(&event).unwrap();
+
+        assert_eq!(comment.action(), Some(&IssueCommentAction::Created));
+        assert_eq!(comment.comment.id, 492700400);
+    }
+}
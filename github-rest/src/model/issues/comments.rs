@@ -1,5 +1,7 @@
 use crate::model::{commits::association::Association, prelude::*, reactions::ReactionRollup, user::SimpleUser};
 
+pub type IssueComments = Vec<IssueComment>;
+
 /// <https://docs.github.com/en/rest/issues/comments#get-an-issue-comment=>
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IssueComment {
@@ -9,6 +11,8 @@ pub struct IssueComment {
     pub issue_url: String,
     pub author_association: Association,
     pub user: Option<SimpleUser>,
+    /// The contents of the comment.
+    pub body: Option<String>,
     pub url: String,
     pub created_at: String,
     pub updated_at: String,
@@ -1,8 +1,12 @@
-use crate::model::{
-    event_types::{macros::repo_origin, RepoEventInfo},
-    issues::{comments::IssueComment, Issue, Label},
-    prelude::*,
-    pull_requests::events::nested::{Change, IssueChanges},
+use crate::{
+    methods::{delete_issue_comment, update_issue_comment, IssueCommentBody, StateReason},
+    model::{
+        event_types::{macros::repo_origin, RepoEventInfo},
+        issues::{comments::IssueComment, Issue, Label},
+        prelude::*,
+        pull_requests::events::nested::{Change, IssueChanges},
+    },
+    GithubRestError, Requester,
 };
 
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#issues>
@@ -17,7 +21,50 @@ pub struct IssueEvent {
 
 as_ref_and_deref!(IssueEvent, RepoEventInfo, event_info);
 
+impl IssueEvent {
+    /// Adds a comment to the issue that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/comments#create-an-issue-comment>
+    pub async fn add_comment<T>(&self, client: &T, body: impl Into<String>) -> Result<IssueComment, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.issue.add_comment(client, body).await
+    }
+
+    /// Closes the issue that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/issues#update-an-issue>
+    pub async fn close_issue<T>(&self, client: &T, reason: Option<StateReason>) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.issue.close(client, reason).await
+    }
+
+    /// Adds labels to the issue that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/labels#add-labels-to-an-issue>
+    pub async fn add_labels<T>(&self, client: &T, labels: Vec<String>) -> Result<Vec<Label>, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.issue.add_labels(client, labels).await
+    }
+
+    /// Assigns users to the issue that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/assignees#add-assignees-to-an-issue>
+    pub async fn add_assignees<T>(&self, client: &T, assignees: Vec<String>) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.issue.add_assignees(client, assignees).await
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum IssueAction {
     Opened,
@@ -41,8 +88,9 @@ pub enum IssueAction {
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#issue_comment>
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueCommentEvent {
-    pub action: IssueAction,
+    pub action: IssueCommentAction,
     pub changes: Option<CommentChanges>,
+    pub issue: Issue,
     pub comment: IssueComment,
     #[serde(flatten)]
     pub event_info: RepoEventInfo,
@@ -50,7 +98,45 @@ pub struct IssueCommentEvent {
 
 as_ref_and_deref!(IssueCommentEvent, RepoEventInfo, event_info);
 
+impl IssueCommentEvent {
+    /// Adds a comment to the issue the current comment was posted on.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/comments#create-an-issue-comment>
+    pub async fn reply<T>(&self, client: &T, body: impl Into<String>) -> Result<IssueComment, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.issue.add_comment(client, body).await
+    }
+
+    /// Replaces the body of the comment that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/comments#update-an-issue-comment>
+    pub async fn update_comment<T>(&self, client: &T, body: impl Into<String>) -> Result<IssueComment, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.issue.owner_and_repo();
+        let body = IssueCommentBody { body: body.into() };
+
+        update_issue_comment(client, owner, repo, self.comment.id, &body).await
+    }
+
+    /// Deletes the comment that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/comments#delete-an-issue-comment>
+    pub async fn delete_comment<T>(&self, client: &T) -> Result<(), GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.issue.owner_and_repo();
+
+        delete_issue_comment(client, owner, repo, self.comment.id).await
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum IssueCommentAction {
     Created,
@@ -92,3 +178,85 @@ pub struct LabelChanges {
 repo_origin!(IssueEvent);
 repo_origin!(LabelEvent);
 repo_origin!(IssueCommentEvent);
+
+#[cfg(test)]
+mod tests {
+    use github_api_octocat::end_points::EndPoints;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::mock::{Interaction, MockRequester};
+
+    const ISSUES: &str = include_str!("../../../tests/fixtures/webhooks/issues.json");
+    const ISSUE_COMMENT: &str = include_str!("../../../tests/fixtures/webhooks/issue_comment.json");
+
+    #[tokio::test]
+    async fn test_issue_event() {
+        let event: IssueEvent = serde_json::from_str(ISSUES).unwrap();
+        let label = serde_json::from_str::<Value>(ISSUES).unwrap()["issue"]["labels"][0].clone();
+
+        assert_eq!(event.action, IssueAction::Opened);
+        assert_eq!(event.issue.number, 1);
+
+        let client = MockRequester::new().with(
+            Interaction::new(EndPoints::PostReposownerrepoIssuesissueNumberLabels(
+                "Codertocat".to_owned(),
+                "Hello-World".to_owned(),
+                "1".to_owned(),
+            ))
+            .body(&json!({ "labels": ["bug"] }))
+            .respond_with(&json!([label])),
+        );
+
+        let labels = event.add_labels(&client, vec!["bug".to_owned()]).await.unwrap();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "bug");
+        assert_eq!(client.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_issue_comment_event() {
+        let event: IssueCommentEvent = serde_json::from_str(ISSUE_COMMENT).unwrap();
+        let comment = serde_json::from_str::<Value>(ISSUE_COMMENT).unwrap()["comment"].clone();
+
+        assert_eq!(event.action, IssueCommentAction::Created);
+        assert_eq!(event.issue.number, 1);
+        assert_eq!(event.comment.id, 492700400);
+
+        let (owner, repo) = (|| "Codertocat".to_owned(), || "Hello-World".to_owned());
+        let client = MockRequester::new()
+            .with(
+                Interaction::new(EndPoints::PostReposownerrepoIssuesissueNumberComments(
+                    owner(),
+                    repo(),
+                    "1".to_owned(),
+                ))
+                .body(&json!({ "body": "Thanks!" }))
+                .respond_with(&comment)
+                .status(201),
+            )
+            .with(
+                Interaction::new(EndPoints::PatchReposownerrepoIssuesCommentscommentId(
+                    owner(),
+                    repo(),
+                    "492700400".to_owned(),
+                ))
+                .body(&json!({ "body": "Fixed" }))
+                .respond_with(&comment),
+            )
+            .with(
+                Interaction::new(EndPoints::DeleteReposownerrepoIssuesCommentscommentId(
+                    owner(),
+                    repo(),
+                    "492700400".to_owned(),
+                ))
+                .status(204),
+            );
+
+        assert_eq!(event.reply(&client, "Thanks!").await.unwrap().id, 492700400);
+        assert_eq!(event.update_comment(&client, "Fixed").await.unwrap().id, 492700400);
+        event.delete_comment(&client).await.unwrap();
+        assert_eq!(client.requests().len(), 3);
+    }
+}
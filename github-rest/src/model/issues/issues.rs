@@ -1,11 +1,20 @@
-use crate::model::{
-    commits::association::Association,
-    issues::{
-        milestones::Milestone,
-        nested::{StringOrLabel, *},
+use crate::{
+    methods::{
+        add_assignees, add_labels, create_issue_comment, get_issue_comments, lock_issue, remove_assignees,
+        remove_label, set_labels, unlock_issue, update_issue, AssigneesBody, GetIssueCommentsBody, IssueCommentBody,
+        LabelsBody, LockIssueBody, LockReason, StateReason, UpdateIssueBody,
     },
-    prelude::*,
-    user::SimpleUser,
+    model::{
+        commits::association::Association,
+        issues::{
+            comments::{IssueComment, IssueComments},
+            milestones::Milestone,
+            nested::{StringOrLabel, *},
+        },
+        prelude::*,
+        user::SimpleUser,
+    },
+    GithubRestError, Requester,
 };
 
 pub type Issues = Vec<Issue>;
@@ -36,6 +45,148 @@ pub struct Issue {
     pub updated_at: String,
 }
 
+impl Issue {
+    /// Gets the owner and name of the repository the current issue belongs
+    /// to, using [`Issue::repository_url`].
+    pub fn owner_and_repo(&self) -> (String, String) {
+        let mut split = self.repository_url.rsplit('/');
+        let repo = split.next().unwrap_or_default().to_owned();
+        let owner = split.next().unwrap_or_default().to_owned();
+
+        (owner, repo)
+    }
+
+    /// Adds a comment to the current issue.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/comments#create-an-issue-comment>
+    pub async fn add_comment<T>(&self, client: &T, body: impl Into<String>) -> Result<IssueComment, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+        let body = IssueCommentBody { body: body.into() };
+
+        create_issue_comment(client, owner, repo, self.number, &body).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/comments#list-issue-comments>
+    pub async fn get_comments<T>(
+        &self,
+        client: &T,
+        options: Option<&GetIssueCommentsBody>,
+    ) -> Result<IssueComments, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        get_issue_comments(client, owner, repo, self.number, options).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/issues#update-an-issue>
+    pub async fn update<T>(&self, client: &T, body: &UpdateIssueBody) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        update_issue(client, owner, repo, self.number, body).await
+    }
+
+    /// Closes the current issue.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/issues#update-an-issue>
+    pub async fn close<T>(&self, client: &T, reason: Option<StateReason>) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        let body = UpdateIssueBody {
+            state: Some(IssueState::Closed),
+            state_reason: reason,
+            ..Default::default()
+        };
+
+        self.update(client, &body).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/issues#lock-an-issue>
+    pub async fn lock<T>(&self, client: &T, reason: Option<LockReason>) -> Result<(), GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+        let body = LockIssueBody { lock_reason: reason };
+
+        lock_issue(client, owner, repo, self.number, &body).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/issues#unlock-an-issue>
+    pub async fn unlock<T>(&self, client: &T) -> Result<(), GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        unlock_issue(client, owner, repo, self.number).await
+    }
+
+    /// Adds labels to the current issue, returning every label it has.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/labels#add-labels-to-an-issue>
+    pub async fn add_labels<T>(&self, client: &T, labels: Vec<String>) -> Result<Vec<Label>, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        add_labels(client, owner, repo, self.number, &LabelsBody { labels }).await
+    }
+
+    /// Replaces the labels of the current issue.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/labels#set-labels-for-an-issue>
+    pub async fn set_labels<T>(&self, client: &T, labels: Vec<String>) -> Result<Vec<Label>, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        set_labels(client, owner, repo, self.number, &LabelsBody { labels }).await
+    }
+
+    /// Removes a label from the current issue, returning the remaining ones.
+    ///
+    /// See also: <https://docs.github.com/en/rest/issues/labels#remove-a-label-from-an-issue>
+    pub async fn remove_label<T>(&self, client: &T, name: impl Into<String>) -> Result<Vec<Label>, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        remove_label(client, owner, repo, self.number, name).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/assignees#add-assignees-to-an-issue>
+    pub async fn add_assignees<T>(&self, client: &T, assignees: Vec<String>) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        add_assignees(client, owner, repo, self.number, &AssigneesBody { assignees }).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/issues/assignees#remove-assignees-from-an-issue>
+    pub async fn remove_assignees<T>(&self, client: &T, assignees: Vec<String>) -> Result<Issue, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        remove_assignees(client, owner, repo, self.number, &AssigneesBody { assignees }).await
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Label {
     pub id: i64,
@@ -10,7 +10,9 @@
 // Temporary
 #![allow(clippy::module_inception)]
 
+pub mod actions;
 pub mod apps;
+pub mod checks;
 pub mod commits;
 pub mod discussions;
 pub mod event_types;
@@ -1,11 +1,17 @@
-use crate::model::{
-    event_types::{macros::repo_origin, RepoEventInfo},
-    issues::{
-        comments::IssueComment,
-        events::{CommentChanges, IssueCommentAction},
+use crate::{
+    methods::{CreateReviewBody, MergeMethod},
+    model::{
+        event_types::{macros::repo_origin, RepoEventInfo},
+        issues::{
+            comments::IssueComment,
+            events::{CommentChanges, IssueCommentAction},
+        },
+        prelude::*,
+        pull_requests::{
+            events::nested::IssueChanges, reviews::PullRequestReview, PullRequest, PullRequestMergeResult,
+        },
     },
-    prelude::*,
-    pull_requests::{events::nested::IssueChanges, PullRequest},
+    GithubRestError, Requester,
 };
 
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#pull_request>
@@ -21,6 +27,37 @@ pub struct PullRequestEvent {
 
 as_ref_and_deref!(PullRequestEvent, RepoEventInfo, event_info);
 
+impl PullRequestEvent {
+    /// Merges the pull request that triggered the event, unless commits have
+    /// been pushed to it since.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#merge-a-pull-request>
+    pub async fn merge_pull_request<T>(
+        &self,
+        client: &T,
+        method: MergeMethod,
+    ) -> Result<PullRequestMergeResult, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.pull_request.merge(client, method).await
+    }
+
+    /// Reviews the pull request that triggered the event.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/reviews#create-a-review-for-a-pull-request>
+    pub async fn review_pull_request<T>(
+        &self,
+        client: &T,
+        body: &CreateReviewBody,
+    ) -> Result<PullRequestReview, GithubRestError>
+    where
+        T: Requester,
+    {
+        self.pull_request.create_review(client, body).await
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, EnumVariantNames)]
 #[serde(rename_all = "snake_case")]
 pub enum PullRequestAction {
@@ -47,7 +84,7 @@ pub enum PullRequestAction {
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#pull_request_review>
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PullRequestReviewEvent {
-    pub action: PullRequestAction,
+    pub action: PullRequestReviewAction,
     pub changes: Option<CommentChanges>,
     pub pull_request: PullRequest,
     pub review: Value,
@@ -58,6 +95,7 @@ pub struct PullRequestReviewEvent {
 as_ref_and_deref!(PullRequestReviewEvent, RepoEventInfo, event_info);
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum PullRequestReviewAction {
     Submitted,
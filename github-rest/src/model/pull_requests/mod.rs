@@ -2,3 +2,4 @@ pub use pull_requests::*;
 
 pub mod events;
 mod pull_requests;
+pub mod reviews;
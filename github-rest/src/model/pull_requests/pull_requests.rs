@@ -1,9 +1,19 @@
-use crate::model::{
-    commits::association::Association,
-    issues::{milestones::Milestone, Label},
-    prelude::*,
-    pull_requests::nested::{AutoMerge, HeadBase, Links},
-    user::SimpleUser,
+use crate::{
+    methods::{
+        create_review, get_pull, get_pull_commits, get_pull_files, merge_pull, request_reviewers, update_pull,
+        CreateReviewBody, MergeMethod, MergePullBody, Pagination, RequestReviewersBody, ReviewEvent, UpdatePullBody,
+    },
+    model::{
+        commits::{association::Association, Commits},
+        issues::{milestones::Milestone, Label},
+        prelude::*,
+        pull_requests::{
+            nested::{AutoMerge, HeadBase, Links},
+            reviews::PullRequestReview,
+        },
+        user::SimpleUser,
+    },
+    GithubRestError, Requester,
 };
 
 pub type Pulls = Vec<SimplePullRequest>;
@@ -50,6 +60,129 @@ pub struct SimplePullRequest {
     pub auto_merge: Option<AutoMerge>,
 }
 
+impl SimplePullRequest {
+    fn owner_and_repo(&self) -> (String, String) {
+        (self.base.repo.owner.login.clone(), self.base.repo.name.clone())
+    }
+
+    /// Gets the full version of the current pull request, which includes
+    /// whether it can be merged.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#get-a-pull-request>
+    pub async fn get_full<T>(&self, client: &T) -> Result<PullRequest, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        get_pull(client, owner, repo, self.number).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#update-a-pull-request>
+    pub async fn update<T>(&self, client: &T, body: &UpdatePullBody) -> Result<PullRequest, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        update_pull(client, owner, repo, self.number, body).await
+    }
+
+    /// Merges the current pull request. Fails with
+    /// [`GithubRestError::Conflict`] if commits have been pushed to it since
+    /// it was fetched.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#merge-a-pull-request>
+    pub async fn merge<T>(&self, client: &T, method: MergeMethod) -> Result<PullRequestMergeResult, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+        let body = MergePullBody {
+            sha: Some(self.head.sha.clone()),
+            merge_method: Some(method),
+            ..Default::default()
+        };
+
+        merge_pull(client, owner, repo, self.number, &body).await
+    }
+
+    /// Gets the files changed by the current pull request.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#list-pull-requests-files>
+    pub async fn get_files<T>(
+        &self,
+        client: &T,
+        params: Option<&Pagination>,
+    ) -> Result<PullRequestFiles, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        get_pull_files(client, owner, repo, self.number, params).await
+    }
+
+    /// Gets the commits of the current pull request.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/pulls#list-commits-on-a-pull-request>
+    pub async fn get_commits<T>(&self, client: &T, params: Option<&Pagination>) -> Result<Commits, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        get_pull_commits(client, owner, repo, self.number, params).await
+    }
+
+    /// Reviews the current pull request.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/reviews#create-a-review-for-a-pull-request>
+    pub async fn create_review<T>(
+        &self,
+        client: &T,
+        body: &CreateReviewBody,
+    ) -> Result<PullRequestReview, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        create_review(client, owner, repo, self.number, body).await
+    }
+
+    /// Approves the head commit of the current pull request.
+    ///
+    /// See also: <https://docs.github.com/en/rest/pulls/reviews#create-a-review-for-a-pull-request>
+    pub async fn approve<T>(&self, client: &T, body: Option<String>) -> Result<PullRequestReview, GithubRestError>
+    where
+        T: Requester,
+    {
+        let review = CreateReviewBody {
+            commit_id: Some(self.head.sha.clone()),
+            body,
+            event: Some(ReviewEvent::Approve),
+            comments: None,
+        };
+
+        self.create_review(client, &review).await
+    }
+
+    /// See also: <https://docs.github.com/en/rest/pulls/review-requests#request-reviewers-for-a-pull-request>
+    pub async fn request_reviewers<T>(
+        &self,
+        client: &T,
+        body: &RequestReviewersBody,
+    ) -> Result<SimplePullRequest, GithubRestError>
+    where
+        T: Requester,
+    {
+        let (owner, repo) = self.owner_and_repo();
+
+        request_reviewers(client, owner, repo, self.number, body).await
+    }
+}
+
 /// <https://docs.github.com/en/rest/pulls/pulls#get-a-pull-request=>
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PullRequest {
@@ -79,6 +212,52 @@ pub enum PullRequestState {
     Open,
 }
 
+/// A file changed by a pull request.
+///
+/// <https://docs.github.com/en/rest/pulls/pulls#list-pull-requests-files>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestFile {
+    pub sha: String,
+    pub filename: String,
+    pub status: FileStatus,
+    pub additions: i64,
+    pub deletions: i64,
+    pub changes: i64,
+    pub blob_url: String,
+    pub raw_url: String,
+    pub contents_url: String,
+    /// Not included for binary files, or if the diff is too large.
+    pub patch: Option<String>,
+    /// Only set if the file has been renamed or copied.
+    pub previous_filename: Option<String>,
+}
+
+pub type PullRequestFiles = Vec<PullRequestFile>;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Removed,
+    #[default]
+    Modified,
+    Renamed,
+    Copied,
+    Changed,
+    Unchanged,
+}
+
+/// The response sent after a pull request has been merged.
+///
+/// <https://docs.github.com/en/rest/pulls/pulls#merge-a-pull-request>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestMergeResult {
+    /// The SHA of the merge commit.
+    pub sha: String,
+    pub merged: bool,
+    pub message: String,
+}
+
 pub mod nested {
     use serde::{Deserialize, Serialize};
 
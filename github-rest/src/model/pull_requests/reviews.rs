@@ -0,0 +1,38 @@
+use crate::model::{commits::association::Association, prelude::*, pull_requests::nested::HRef, user::SimpleUser};
+
+/// <https://docs.github.com/en/rest/pulls/reviews#get-a-review-for-a-pull-request>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestReview {
+    pub id: i64,
+    pub node_id: String,
+    pub user: Option<SimpleUser>,
+    pub body: String,
+    pub state: ReviewState,
+    pub html_url: String,
+    pub pull_request_url: String,
+    #[serde(rename = "_links")]
+    pub links: ReviewLinks,
+    pub submitted_at: Option<String>,
+    /// The SHA of the commit that was reviewed.
+    pub commit_id: Option<String>,
+    pub author_association: Association,
+}
+
+pub type PullRequestReviews = Vec<PullRequestReview>;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    #[default]
+    Commented,
+    Dismissed,
+    Pending,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewLinks {
+    pub html: HRef,
+    pub pull_request: HRef,
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, EnumVariantNames};
+
+use crate::model::{
+    commits::nested::{Parent, Tree, Verification},
+    user::GitUser,
+};
+
+/// The kind of object a [`Content`] describes.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, EnumVariantNames)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ContentType {
+    #[default]
+    File,
+    Dir,
+    Symlink,
+    Submodule,
+}
+
+/// A file, directory, symlink or submodule in a repository.
+///
+/// Only files fetched on their own come with a `content`. Entries of a
+/// directory listing don't.
+///
+/// <https://docs.github.com/en/rest/repos/contents#get-repository-content>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Content {
+    #[serde(rename = "type")]
+    pub type_field: ContentType,
+    pub size: i64,
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    /// The contents of the file, encoded as described by
+    /// [`Content::encoding`].
+    pub content: Option<String>,
+    /// `base64` for files, or `none` for files larger than 1 MB, in which case
+    /// `content` is empty.
+    pub encoding: Option<String>,
+    /// The path a symlink points to.
+    pub target: Option<String>,
+    pub submodule_git_url: Option<String>,
+    pub url: String,
+    pub git_url: Option<String>,
+    pub html_url: Option<String>,
+    pub download_url: Option<String>,
+    #[serde(rename = "_links")]
+    pub links: ContentLinks,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentLinks {
+    #[serde(rename = "self")]
+    pub self_field: String,
+    pub git: Option<String>,
+    pub html: Option<String>,
+}
+
+#[cfg(feature = "client")]
+impl Content {
+    /// Decodes [`Content::content`]. Returns `None` if there is no content,
+    /// or if it isn't valid Base64.
+    pub fn decode_content(&self) -> Option<Vec<u8>> {
+        use base64::Engine;
+
+        if self.encoding.as_deref().is_some_and(|e| e != "base64") {
+            return None;
+        }
+
+        // GitHub wraps the encoded content at 60 characters
+        let encoded = self
+            .content
+            .as_ref()?
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect::<String>();
+
+        base64::engine::general_purpose::STANDARD.decode(encoded).ok()
+    }
+
+    /// Same as [`Content::decode_content`], but also returns `None` if the
+    /// content isn't valid UTF-8.
+    pub fn decode_content_string(&self) -> Option<String> {
+        String::from_utf8(self.decode_content()?).ok()
+    }
+}
+
+/// The response of `GET /repos/{owner}/{repo}/contents/{path}`, which depends
+/// on whether `path` is a directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Contents {
+    Directory(Vec<Content>),
+    File(Box<Content>),
+}
+
+impl Contents {
+    /// Gets the [`Content`] if the path wasn't a directory.
+    pub fn file(&self) -> Option<&Content> {
+        match self {
+            Self::File(content) => Some(content),
+            Self::Directory(_) => None,
+        }
+    }
+
+    /// Gets the entries if the path was a directory.
+    pub fn directory(&self) -> Option<&[Content]> {
+        match self {
+            Self::File(_) => None,
+            Self::Directory(entries) => Some(entries),
+        }
+    }
+}
+
+/// The response sent after a file has been created, updated or deleted.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileCommit {
+    /// The new file. `None` if it has been deleted.
+    pub content: Option<Content>,
+    pub commit: GitCommit,
+}
+
+/// A Git commit object, as returned by the Git database and contents APIs.
+///
+/// <https://docs.github.com/en/rest/git/commits#get-a-commit-object>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitCommit {
+    pub sha: String,
+    pub node_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub author: GitUser,
+    pub committer: GitUser,
+    pub message: String,
+    pub tree: Tree,
+    pub parents: Vec<Parent>,
+    pub verification: Option<Verification>,
+}
+
+#[cfg(feature = "client")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_content() {
+        let contents: Contents = serde_json::from_str(
+            r#"{
+                "type": "file",
+                "encoding": "base64",
+                "size": 12,
+                "name": "CHANGELOG.md",
+                "path": "CHANGELOG.md",
+                "content": "IyBDaGFu\nZ2Vsb2cK\n",
+                "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                "url": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/CHANGELOG.md",
+                "git_url": null,
+                "html_url": null,
+                "download_url": null,
+                "_links": {
+                    "self": "https://api.github.com/repos/octocat-rs/octocat-rs/contents/CHANGELOG.md"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let file = contents.file().unwrap();
+
+        assert_eq!(file.type_field, ContentType::File);
+        assert_eq!(file.decode_content_string().as_deref(), Some("# Changelog\n"));
+        assert!(serde_json::from_str::<Contents>("[]").unwrap().directory().is_some());
+    }
+}
@@ -1,5 +1,6 @@
 pub use repositories::*;
 
+pub mod contents;
 pub mod events;
 mod repositories;
 pub mod security_advisory;
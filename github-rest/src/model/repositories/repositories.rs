@@ -3,7 +3,11 @@ use crate::model::{prelude::*, repositories::nested::*, user::SimpleUser};
 /// <https://docs.github.com/en/rest/repos/repos#get-a-repository=>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Repository {
+    /// Not included in webhook payloads.
+    #[serde(default)]
     pub network_count: i64,
+    /// Not included in webhook payloads.
+    #[serde(default)]
     pub subscribers_count: i64,
     #[serde(flatten)]
     pub common: Repo,
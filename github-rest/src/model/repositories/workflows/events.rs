@@ -1,7 +1,11 @@
-use crate::model::{
-    event_types::{macros::repo_origin, RepoEventInfo},
-    prelude::*,
-    repositories::workflows::{events::nested::*, PageBuild, Workflow},
+use crate::{
+    methods::{update_check_run, UpdateCheckRunBody},
+    model::{
+        event_types::{macros::repo_origin, RepoEventInfo},
+        prelude::*,
+        repositories::workflows::{events::nested::*, PageBuild, Workflow},
+    },
+    GithubRestError, Requester,
 };
 
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#workflow_dispatch>
@@ -50,6 +54,26 @@ pub struct CheckRunEvent {
 
 as_ref_and_deref!(CheckRunEvent, RepoEventInfo, event_info);
 
+impl CheckRunEvent {
+    /// Updates the check run that triggered the event, e.g. after it has been
+    /// rerequested.
+    ///
+    /// See also: <https://docs.github.com/en/rest/checks/runs#update-a-check-run>
+    pub async fn update_check_run<T>(&self, client: &T, body: &UpdateCheckRunBody) -> Result<CheckRun, GithubRestError>
+    where
+        T: Requester,
+    {
+        update_check_run(
+            client,
+            self.event_info.repository.owner.login.clone(),
+            self.event_info.repository.name.clone(),
+            self.check_run.id,
+            body,
+        )
+        .await
+    }
+}
+
 /// <https://docs.github.com/en/developers/webhooks-and-events/webhooks/webhook-events-and-payloads#check_suite>
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckSuiteEvent {
@@ -82,6 +106,7 @@ as_ref_and_deref!(PageBuildEvent, RepoEventInfo, event_info);
 
 pub mod nested {
     use crate::model::{
+        checks::{CheckRunConclusion, CheckRunStatus},
         prelude::*,
         repositories::{events::nested::HeadCommit, Repository},
         user::SimpleUser,
@@ -173,17 +198,17 @@ pub mod nested {
         pub name: String,
         pub node_id: String,
         pub head_sha: String,
-        pub external_id: String,
+        pub external_id: Option<String>,
         pub url: String,
-        pub html_url: String,
-        pub details_url: String,
-        pub status: String,
-        pub conclusion: String,
-        pub started_at: String,
-        pub completed_at: String,
+        pub html_url: Option<String>,
+        pub details_url: Option<String>,
+        pub status: CheckRunStatus,
+        pub conclusion: Option<CheckRunConclusion>,
+        pub started_at: Option<String>,
+        pub completed_at: Option<String>,
         pub output: Output,
         pub check_suite: CheckSuite,
-        pub app: App,
+        pub app: Option<App>,
         pub pull_requests: Vec<Value>,
     }
 
@@ -206,7 +231,10 @@ pub mod nested {
         pub annotations_url: String,
     }
 
+    /// Check runs returned by the REST API only include the `id` of their
+    /// check suite.
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
     pub struct CheckSuite {
         pub id: i64,
         pub node_id: String,
@@ -230,7 +258,7 @@ pub mod nested {
         pub node_id: String,
         pub owner: SimpleUser,
         pub name: String,
-        pub description: String,
+        pub description: Option<String>,
         pub external_url: String,
         pub html_url: String,
         pub created_at: String,
@@ -239,7 +267,9 @@ pub mod nested {
         pub events: Vec<String>,
     }
 
+    /// Only the permissions granted to the app are present.
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
     pub struct Permissions {
         pub actions: String,
         pub administration: String,
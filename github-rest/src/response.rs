@@ -0,0 +1,219 @@
+//! Contains the [`Response`] type returned by
+//! [`Requester::raw_req_with_meta`].
+//!
+//! [`Requester::raw_req_with_meta`]: crate::Requester::raw_req_with_meta
+
+use std::collections::HashMap;
+
+use crate::GithubRestError;
+
+/// A response body along with the status code and headers GitHub sent back
+/// with it.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub body: T,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// Response headers. Names are stored in lowercase.
+    pub headers: HashMap<String, String>,
+}
+
+impl<T> Response<T> {
+    /// Creates a `200 OK` [`Response`] without any headers.
+    pub fn new(body: T) -> Self {
+        Self {
+            body,
+            status: 200,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Sets the status code of the current [`Response`].
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Adds a header to the current [`Response`].
+    pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        self.headers.insert(name.as_ref().to_lowercase(), value.into());
+        self
+    }
+
+    /// Gets the value of a header. The lookup is case-insensitive.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+
+    /// The `ETag` header, used for conditional requests.
+    pub fn etag(&self) -> Option<&str> {
+        self.header("etag")
+    }
+
+    /// The `Last-Modified` header, used for conditional requests.
+    pub fn last_modified(&self) -> Option<&str> {
+        self.header("last-modified")
+    }
+
+    /// The `Link` header, used for pagination.
+    pub fn link(&self) -> Option<&str> {
+        self.header("link")
+    }
+
+    /// The `X-GitHub-Request-Id` header. Include this when contacting GitHub
+    /// support about a request.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-github-request-id")
+    }
+
+    /// The `Retry-After` header in seconds. Usually only sent when a secondary
+    /// rate limit has been hit.
+    pub fn retry_after(&self) -> Option<u64> {
+        self.header("retry-after")?.trim().parse().ok()
+    }
+
+    /// The primary rate limit status, parsed from the `X-RateLimit-*`
+    /// headers.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        let parse = |name: &str| self.header(name)?.trim().parse::<u64>().ok();
+
+        Some(RateLimit {
+            limit: parse("x-ratelimit-limit")?,
+            remaining: parse("x-ratelimit-remaining")?,
+            used: parse("x-ratelimit-used"),
+            reset: parse("x-ratelimit-reset")?,
+            resource: self.header("x-ratelimit-resource").map(ToOwned::to_owned),
+        })
+    }
+
+    /// Returns a [`GithubRestError::RateLimited`] if the current [`Response`]
+    /// indicates that a primary or secondary rate limit has been exceeded.
+    ///
+    /// See also: <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#exceeding-the-rate-limit>
+    pub fn rate_limited(&self) -> Option<GithubRestError> {
+        if self.status != 403 && self.status != 429 {
+            return None;
+        }
+
+        let rate_limit = self.rate_limit();
+        let retry_after = self.retry_after();
+
+        if retry_after.is_none() && rate_limit.as_ref().is_none_or(|r| r.remaining > 0) {
+            return None;
+        }
+
+        Some(GithubRestError::RateLimited {
+            reset_at: rate_limit.map(|r| r.reset),
+            retry_after,
+        })
+    }
+
+    /// Whether the status code is in the `2xx` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Whether the status code is `304 Not Modified`, which is only sent in
+    /// response to conditional requests.
+    pub fn is_not_modified(&self) -> bool {
+        self.status == 304
+    }
+
+    /// Transforms the body of a [`Response`], keeping its status and headers.
+    pub fn map<A>(self, f: impl FnOnce(T) -> A) -> Response<A> {
+        Response {
+            body: f(self.body),
+            status: self.status,
+            headers: self.headers,
+        }
+    }
+}
+
+/// The primary rate limit status sent with every API response.
+///
+/// See also: <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#rate-limit-http-headers>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of requests you're permitted to make per hour.
+    pub limit: u64,
+    /// The number of requests remaining in the current rate limit window.
+    pub remaining: u64,
+    /// The number of requests you've made in the current rate limit window.
+    pub used: Option<u64>,
+    /// The time at which the current rate limit window resets in UTC epoch
+    /// seconds.
+    pub reset: u64,
+    /// The rate limit resource that the request counted against.
+    pub resource: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit() {
+        let res = Response::new(String::new())
+            .with_header("X-RateLimit-Limit", "5000")
+            .with_header("X-RateLimit-Remaining", "4999")
+            .with_header("X-RateLimit-Used", "1")
+            .with_header("X-RateLimit-Reset", "1372700873")
+            .with_header("X-RateLimit-Resource", "core")
+            .with_header("X-GitHub-Request-Id", "CDEF:1234:5678");
+
+        assert_eq!(
+            res.rate_limit(),
+            Some(RateLimit {
+                limit: 5000,
+                remaining: 4999,
+                used: Some(1),
+                reset: 1372700873,
+                resource: Some("core".to_owned()),
+            })
+        );
+        assert_eq!(res.request_id(), Some("CDEF:1234:5678"));
+        assert!(Response::new(()).rate_limit().is_none());
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let res = Response::new(String::new())
+            .with_status(403)
+            .with_header("X-RateLimit-Limit", "5000")
+            .with_header("X-RateLimit-Remaining", "0")
+            .with_header("X-RateLimit-Reset", "1372700873");
+
+        assert!(matches!(
+            res.rate_limited(),
+            Some(GithubRestError::RateLimited {
+                reset_at: Some(1372700873),
+                retry_after: None
+            })
+        ));
+
+        let res = Response::new(String::new())
+            .with_status(429)
+            .with_header("Retry-After", "60");
+
+        assert!(matches!(
+            res.rate_limited(),
+            Some(GithubRestError::RateLimited {
+                reset_at: None,
+                retry_after: Some(60)
+            })
+        ));
+
+        // A 403 caused by missing permissions
+        let res = Response::new(String::new())
+            .with_status(403)
+            .with_header("X-RateLimit-Limit", "5000")
+            .with_header("X-RateLimit-Remaining", "4999")
+            .with_header("X-RateLimit-Reset", "1372700873");
+
+        assert!(res.rate_limited().is_none());
+    }
+}
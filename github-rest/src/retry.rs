@@ -0,0 +1,252 @@
+//! Contains [`RetryPolicy`], used to retry requests that failed for transient
+//! reasons.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use github_api_octocat::end_points::Methods;
+
+/// Configures how requests that failed for transient reasons (connection
+/// resets, `502 Bad Gateway`, ...) are retried.
+///
+/// By default only idempotent requests (`GET`, `PUT` and `DELETE`) are
+/// retried, as retrying a `POST` could, for example, create the same issue
+/// twice.
+///
+/// Retries are delayed using exponential backoff with jitter: the `n`th retry
+/// waits for a random duration between half of and the full
+/// `initial_backoff * 2^(n - 1)`, capped at `max_backoff`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent, including the first
+    /// attempt. Default: 3.
+    pub max_attempts: u32,
+    /// The delay before the first retry. Default: 500 milliseconds.
+    pub initial_backoff: Duration,
+    /// The longest delay between two attempts. Default: 30 seconds.
+    pub max_backoff: Duration,
+    /// The status codes that are considered transient. Default: 500, 502, 503
+    /// and 504.
+    pub retryable_statuses: Vec<u16>,
+    /// Whether `POST` and `PATCH` requests should be retried as well. Default:
+    /// false.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retryable_statuses: vec![500, 502, 503, 504],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a [`RetryPolicy`] that never retries anything.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of times a request is sent, including the
+    /// first attempt.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest delay between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the status codes that are considered transient.
+    pub fn retryable_statuses(mut self, statuses: impl IntoIterator<Item = u16>) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Sets whether `POST` and `PATCH` requests should be retried as well.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Whether a request that was sent using `method` should be retried.
+    /// `status` is `None` if no response was received at all.
+    ///
+    /// This does not take [`RetryPolicy::max_attempts`] into account.
+    pub fn should_retry(&self, method: &Methods, status: Option<u16>) -> bool {
+        let idempotent = matches!(method, Methods::Get | Methods::Put | Methods::Delete);
+
+        if !idempotent && !self.retry_non_idempotent {
+            return false;
+        }
+
+        status.is_none_or(|s| self.retryable_statuses.contains(&s))
+    }
+
+    /// How long to wait before the given retry. The first retry is `1`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_backoff);
+
+        exp / 2 + (exp / 2).mul_f64(jitter())
+    }
+
+    /// Sends a request, retrying it according to the current policy.
+    ///
+    /// Requests with a streaming body can't be cloned and are only sent once.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn send(
+        &self,
+        method: &Methods,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 1;
+
+        loop {
+            let res = match req.try_clone() {
+                Some(req) => req.send().await,
+                None => return req.send().await,
+            };
+
+            let status = match &res {
+                Ok(res) => Some(res.status().as_u16()),
+                Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => None,
+                Err(_) => return res,
+            };
+
+            if attempt >= self.max_attempts || !self.should_retry(method, status) {
+                return res;
+            }
+
+            tokio::time::sleep(self.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Returns a random number in `0.0..=1.0`.
+fn jitter() -> f64 {
+    RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use super::*;
+
+    /// Starts a mock HTTP server that responds with the given status codes in
+    /// order, and returns its address along with the number of requests it
+    /// received.
+    fn mock_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let thread_hits = hits.clone();
+
+        std::thread::spawn(move || {
+            for (stream, status) in listener.incoming().zip(statuses) {
+                let mut stream = stream.unwrap();
+                let mut buf = [0; 4096];
+                let _ = stream.read(&mut buf).unwrap();
+
+                thread_hits.fetch_add(1, Ordering::SeqCst);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{{}}"
+                )
+                .unwrap();
+            }
+        });
+
+        (addr, hits)
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(5))
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+
+        for retry in 1..10 {
+            let backoff = policy.backoff(retry);
+            let exp = (policy.initial_backoff * 2u32.pow(retry - 1)).min(policy.max_backoff);
+
+            assert!(backoff >= exp / 2 && backoff <= exp);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_get() {
+        let (addr, hits) = mock_server(vec![502, 503, 200]);
+
+        let res = fast_policy()
+            .send(&Methods::Get, reqwest::Client::new().get(addr))
+            .await
+            .unwrap();
+
+        assert_eq!(res.status().as_u16(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_max_attempts() {
+        let (addr, hits) = mock_server(vec![502, 502, 502]);
+
+        let res = fast_policy()
+            .max_attempts(2)
+            .send(&Methods::Delete, reqwest::Client::new().delete(addr))
+            .await
+            .unwrap();
+
+        assert_eq!(res.status().as_u16(), 502);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_post() {
+        let (addr, hits) = mock_server(vec![502, 200]);
+
+        let res = fast_policy()
+            .send(&Methods::Post, reqwest::Client::new().post(addr).body("{}"))
+            .await
+            .unwrap();
+
+        assert_eq!(res.status().as_u16(), 502);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+}
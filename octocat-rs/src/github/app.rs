@@ -0,0 +1,123 @@
+//! Contains the helpers used by [`HttpClient`] to authenticate as a GitHub
+//! App or as one of its installations.
+//!
+//! [`HttpClient`]: crate::github::HttpClient
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+
+use github_rest::GithubRestError;
+
+/// How long an App JWT is valid for. GitHub rejects JWTs that expire more than
+/// 10 minutes in the future.
+const JWT_LIFETIME: u64 = 9 * 60;
+
+/// Cached installation tokens are refreshed once they expire in less than
+/// this many seconds.
+const REFRESH_MARGIN: u64 = 5 * 60;
+
+#[derive(Serialize, Deserialize)]
+struct Claims {
+    iat: u64,
+    exp: u64,
+    iss: String,
+}
+
+/// Mints a JWT that authenticates as the GitHub App with the given ID.
+///
+/// See also: <https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/generating-a-json-web-token-jwt-for-a-github-app>
+pub(crate) fn app_jwt(app_id: u64, private_key_pem: &str, now: u64) -> Result<String, GithubRestError> {
+    let key = EncodingKey::from_rsa_pem(private_key_pem.as_bytes())
+        .map_err(|e| GithubRestError::AuthError(format!("invalid GitHub App private key: {e}")))?;
+
+    let claims = Claims {
+        // Issued in the past to allow for clock drift
+        iat: now.saturating_sub(60),
+        exp: now + JWT_LIFETIME,
+        iss: app_id.to_string(),
+    };
+
+    jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &key)
+        .map_err(|e| GithubRestError::AuthError(format!("failed to sign GitHub App JWT: {e}")))
+}
+
+/// An installation access token, as returned by
+/// `POST /app/installations/{installation_id}/access_tokens`.
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct InstallationToken {
+    pub(crate) token: String,
+    expires_at: String,
+}
+
+impl InstallationToken {
+    /// Whether the token should be replaced by a new one.
+    pub(crate) fn needs_refresh(&self, now: u64) -> bool {
+        parse_timestamp(&self.expires_at).is_none_or(|expires_at| expires_at <= now + REFRESH_MARGIN)
+    }
+}
+
+/// Parses a `YYYY-MM-DDTHH:MM:SSZ` timestamp into UTC epoch seconds.
+fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (y, m, d) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hh, mm, ss) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    // Days since the epoch, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = u64::try_from(era * 146097 + doe - 719468).ok()?;
+
+    Some(days * 86400 + hh * 3600 + mm * 60 + ss)
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{DecodingKey, Validation};
+
+    use super::*;
+
+    const TEST_KEY: &str = include_str!("../../test-data/app_test_key.pem");
+
+    #[test]
+    fn test_app_jwt() {
+        let jwt = app_jwt(12345, TEST_KEY, 1_700_000_000).unwrap();
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.insecure_disable_signature_validation();
+        validation.validate_exp = false;
+
+        let claims = jsonwebtoken::decode::<Claims>(&jwt, &DecodingKey::from_secret(&[]), &validation)
+            .unwrap()
+            .claims;
+
+        assert_eq!(claims.iss, "12345");
+        assert_eq!(claims.iat, 1_700_000_000 - 60);
+        assert_eq!(claims.exp, 1_700_000_000 + JWT_LIFETIME);
+
+        assert!(matches!(
+            app_jwt(12345, "not a key", 0),
+            Err(GithubRestError::AuthError(_))
+        ));
+    }
+
+    #[test]
+    fn test_needs_refresh() {
+        assert_eq!(parse_timestamp("2016-07-11T22:14:10Z"), Some(1468275250));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+
+        let token = InstallationToken {
+            token: String::new(),
+            expires_at: "2016-07-11T22:14:10Z".to_owned(),
+        };
+
+        assert!(!token.needs_refresh(1468275250 - REFRESH_MARGIN - 1));
+        assert!(token.needs_refresh(1468275250 - REFRESH_MARGIN));
+    }
+}
@@ -2,65 +2,69 @@
 //! ([`Client`]).
 
 #[cfg(all(target_family = "wasm", feature = "workers"))]
-use std::str::FromStr;
-
-use std::{fmt::Debug, sync::Arc};
+use std::time::{Duration, UNIX_EPOCH};
+#[cfg(feature = "native")]
+use std::{convert::Infallible, net::SocketAddr, time::Duration};
+use std::{fmt::Debug, str::FromStr, sync::Arc, time::SystemTime};
 
 use async_trait::async_trait;
-use github_api_octocat::end_points::EndPoints;
+#[cfg(feature = "native")]
+use futures::Future;
+#[cfg(feature = "tls")]
+use futures::FutureExt;
+use futures::{stream::FuturesUnordered, StreamExt};
+use github_api_octocat::end_points::{EndPoints, Methods};
 use serde::{de::DeserializeOwned, Serialize};
-
-#[cfg(all(feature = "secrets", not(target_family = "wasm")))]
-use bytes::Bytes;
-
-#[cfg(feature = "secrets")]
-use hmac::{Hmac, Mac};
-#[cfg(feature = "secrets")]
-use sha2::Sha256;
+use serde_json::Value;
 
 #[cfg(feature = "native")]
-use tokio::sync::mpsc;
+use tokio::sync::{oneshot, Semaphore};
 #[cfg(feature = "native")]
-use warp::Filter;
+use warp::{
+    http::{HeaderMap, StatusCode},
+    hyper::body::Bytes,
+    reply, Filter,
+};
 
+#[cfg(feature = "native")]
+use github_rest::RetryPolicy;
 use github_rest::{
-    model::{
-        apps::events::{AppAuthorizationEvent, InstallationEvent, InstallationRepositoriesEvent},
-        commits::events::{CommitCommentEvent, StatusEvent},
-        discussions::events::{DiscussionCommentEvent, DiscussionEvent},
-        event_types::EventTypes,
-        issues::events::{IssueCommentEvent, IssueEvent, LabelEvent},
-        misc::events::{DeploymentEvent, DeploymentStatusEvent, MarketplacePurchaseEvent, MetaEvent, SponsorshipEvent},
-        organizations::events::{MembershipEvent, OrgBlockEvent, OrganizationEvent, TeamEvent},
-        pull_requests::events::{PullRequestEvent, PullRequestReviewCommentEvent, PullRequestReviewEvent},
-        releases::events::{CreateEvent, DeleteEvent, ReleaseEvent},
-        repositories::{
-            events::{
-                BranchProtectionRuleEvent, CodeScanningAlertEvent, DeployKeyEvent, ForkEvent, MemberEvent,
-                MilestoneEvent, PackageEvent, PingEvent, ProjectCardEvent, ProjectColumnEvent, ProjectEvent,
-                PublicEvent, PushEvent, RepositoryDispatchEvent, RepositoryEvent, RepositoryImportEvent,
-                RepositoryVulnerabilityAlertEvent, SecretScanningAlertEvent, StarEvent, TeamAddEvent, WatchEvent,
-            },
-            security_advisory::events::SecurityAdvisoryEvent,
-            wiki::events::GollumEvent,
-            workflows::events::{
-                CheckRunEvent, CheckSuiteEvent, PageBuildEvent, WorkflowDispatchEvent, WorkflowJobEvent,
-                WorkflowRunEvent,
-            },
-        },
-    },
-    GithubRestError, Requester,
+    model::event_types::{EventTypes, WebhookEvent},
+    GithubRestError, Requester, Response,
 };
 
-use crate::github::{handler::EventHandler, util::Authorization, DefaultEventHandler, HttpClient};
-
 #[cfg(feature = "secrets")]
-type HmacSha256 = Hmac<Sha256>;
+use crate::github::secrets::{GITHUB_SIGNATURE_256_HEADER, GITHUB_SIGNATURE_HEADER};
+#[cfg(all(target_family = "wasm", feature = "workers"))]
+use crate::github::webhook::GITHUB_EVENT_HEADER;
+#[cfg(feature = "tls")]
+use crate::github::TlsConfig;
+use crate::github::{
+    command::BoxFuture, handler::EventHandler, util::Authorization, Command, DefaultEventHandler, HttpClient,
+    IdempotencyStore, RateLimitConfig, WebhookContext, WebhookStatus,
+};
+#[cfg(feature = "native")]
+use crate::github::{ListenerHandle, DEFAULT_DRAIN_TIMEOUT};
 
-const GITHUB_EVENT_HEADER: &str = "X-GitHub-Event";
+/// A future that calls [`EventHandler::on_parse_error`].
+type DispatchError<M> = BoxFuture<Command<M>>;
 
-#[cfg(feature = "secrets")]
-const GITHUB_SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+/// The default maximum number of webhook deliveries handled at once.
+#[cfg(feature = "native")]
+pub const DEFAULT_MAX_CONCURRENCY: usize = 64;
+
+/// Checks a maximum number of concurrent deliveries, capping it at
+/// [`Semaphore::MAX_PERMITS`].
+///
+/// # Panics
+///
+/// Panics if `max_concurrency` is 0, as no delivery would ever be handled.
+#[cfg(feature = "native")]
+pub(crate) fn checked_max_concurrency(max_concurrency: usize) -> usize {
+    assert!(max_concurrency > 0, "max_concurrency must be greater than 0");
+
+    max_concurrency.min(Semaphore::MAX_PERMITS)
+}
 
 #[async_trait]
 pub trait GitHubClient: Requester + Sized {
@@ -81,8 +85,17 @@ where
     T: Debug + EventHandler<GitHubClient = Client<T>> + Send + Sync,
 {
     handler: T,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
     #[cfg(feature = "native")]
     max_payload_size: u64,
+    #[cfg(feature = "native")]
+    max_concurrency: usize,
+    #[cfg(feature = "native")]
+    bind_addr: Option<SocketAddr>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+    #[cfg(feature = "native")]
+    drain_timeout: Duration,
     http_client: HttpClient,
 }
 
@@ -109,17 +122,60 @@ impl<C> Requester for Client<C>
 where
     C: Send + Sync + Debug + EventHandler<GitHubClient = Client<C>>,
 {
-    async fn raw_req<T, V>(
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        self.http_client.raw_req(url, query, body).await
+    }
+
+    async fn raw_req_with_meta<T, V>(
         &self,
         url: EndPoints,
         query: Option<&T>,
         body: Option<V>,
-    ) -> Result<String, GithubRestError>
+    ) -> Result<Response<String>, GithubRestError>
     where
         T: Serialize + ?Sized + Send + Sync,
         V: Into<Self::Body> + Send,
     {
-        self.http_client.raw_req(url, query, body).await
+        self.http_client.raw_req_with_meta(url, query, body).await
+    }
+
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        self.http_client.raw_req_with_headers(url, query, body, headers).await
+    }
+
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+    {
+        self.http_client.raw_req_bytes(url, query).await
+    }
+
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        self.http_client.raw_req_path(method, path, query, body).await
     }
 
     async fn req<T, V, A: DeserializeOwned>(
@@ -136,348 +192,277 @@ where
     }
 }
 
-macro_rules! event_handle {
-    ($ev:ident) => {
-        match $ev {
-            EventTypes::Push => {
-                event_push!(push_event, PushEvent)
-            }
-            EventTypes::GithubAppAuthorization => {
-                event_push!(app_authorization_event, AppAuthorizationEvent)
-            }
-            EventTypes::Installation => {
-                event_push!(installation_event, InstallationEvent)
-            }
-            EventTypes::InstallationRepositories => {
-                event_push!(installation_repositories_event, InstallationRepositoriesEvent)
-            }
-            EventTypes::DeployKey => {
-                event_push!(deploy_key_event, DeployKeyEvent)
-            }
-            EventTypes::Gollum => {
-                event_push!(gollum_event, GollumEvent)
-            }
-            EventTypes::Member => {
-                event_push!(member_event, MemberEvent)
-            }
-            EventTypes::Milestone => {
-                event_push!(milestone_event, MilestoneEvent)
-            }
-            EventTypes::Public => {
-                event_push!(public_event, PublicEvent)
-            }
-            EventTypes::Release => {
-                event_push!(release_event, ReleaseEvent)
-            }
-            EventTypes::Repository => {
-                event_push!(repository_event, RepositoryEvent)
-            }
-            EventTypes::RepositoryDispatch => {
-                event_push!(repository_dispatch_event, RepositoryDispatchEvent)
-            }
-            EventTypes::RepositoryImport => {
-                event_push!(repository_import_event, RepositoryImportEvent)
-            }
-            EventTypes::RepositoryVulnerabilityAlert => {
-                event_push!(repository_vulnerability_alert, RepositoryVulnerabilityAlertEvent)
-            }
-            EventTypes::SecretScanningAlert => {
-                event_push!(secret_scanning_alert, SecretScanningAlertEvent)
-            }
-            EventTypes::SecurityAdvisory => {
-                event_push!(security_advisory_event, SecurityAdvisoryEvent)
-            }
-            EventTypes::Star => {
-                event_push!(star_event, StarEvent)
-            }
-            EventTypes::Watch => {
-                event_push!(watch_event, WatchEvent)
-            }
-            EventTypes::PullRequest => {
-                event_push!(pull_request_event, PullRequestEvent)
-            }
-            EventTypes::PullRequestReview => {
-                event_push!(pull_request_review_event, PullRequestReviewEvent)
-            }
-            EventTypes::PullRequestReviewComment => {
-                event_push!(pull_request_review_comment_event, PullRequestReviewCommentEvent)
-            }
-            EventTypes::CommitComment => {
-                event_push!(commit_comment_event, CommitCommentEvent)
-            }
-            EventTypes::Status => {
-                event_push!(status_event, StatusEvent)
-            }
-            EventTypes::IssueComment => {
-                event_push!(issue_comment_event, IssueCommentEvent)
-            }
-            EventTypes::Issues => {
-                event_push!(issue_event, IssueEvent)
-            }
-            EventTypes::Label => {
-                event_push!(label_event, LabelEvent)
-            }
-            EventTypes::Discussion => {
-                event_push!(discussion_event, DiscussionEvent)
-            }
-            EventTypes::DiscussionComment => {
-                event_push!(discussion_comment_event, DiscussionCommentEvent)
-            }
-            EventTypes::BranchProtectionRule => {
-                event_push!(branch_protection_rule_event, BranchProtectionRuleEvent)
-            }
-            EventTypes::Create => {
-                event_push!(tag_created, CreateEvent)
-            }
-            EventTypes::Delete => {
-                event_push!(tag_deleted, DeleteEvent)
-            }
-            EventTypes::Fork => {
-                event_push!(repository_forked, ForkEvent)
-            }
-            EventTypes::CheckRun => {
-                event_push!(check_run, CheckRunEvent)
-            }
-            EventTypes::CheckSuite => {
-                event_push!(check_suite_event, CheckSuiteEvent)
-            }
-            EventTypes::CodeScanningAlert => {
-                event_push!(code_scanning_alert, CodeScanningAlertEvent)
-            }
-            EventTypes::Deployment => {
-                event_push!(deployment_event, DeploymentEvent)
-            }
-            EventTypes::DeploymentStatus => {
-                event_push!(deployment_status_event, DeploymentStatusEvent)
-            }
-            EventTypes::PageBuild => {
-                event_push!(page_build_event, PageBuildEvent)
-            }
-            EventTypes::WorkflowDispatch => {
-                event_push!(workflow_dispatch_event, WorkflowDispatchEvent)
-            }
-            EventTypes::WorkflowJob => {
-                event_push!(workflow_job, WorkflowJobEvent)
-            }
-            EventTypes::WorkflowRun => {
-                event_push!(workflow_run, WorkflowRunEvent)
-            }
-            EventTypes::Membership => {
-                event_push!(membership_event, MembershipEvent)
-            }
-            EventTypes::OrgBlock => {
-                event_push!(org_block_event, OrgBlockEvent)
-            }
-            EventTypes::Organization => {
-                event_push!(organization_event, OrganizationEvent)
-            }
-            EventTypes::Team => {
-                event_push!(team_event, TeamEvent)
-            }
-            EventTypes::TeamAdd => {
-                event_push!(team_add_event, TeamAddEvent)
-            }
-            EventTypes::Project => {
-                event_push!(project_event, ProjectEvent)
-            }
-            EventTypes::ProjectCard => {
-                event_push!(project_card_event, ProjectCardEvent)
-            }
-            EventTypes::ProjectColumn => {
-                event_push!(project_column_event, ProjectColumnEvent)
-            }
-            EventTypes::MarketplacePurchase => {
-                event_push!(marketplace_purchase_event, MarketplacePurchaseEvent)
-            }
-            EventTypes::Meta => {
-                event_push!(meta_event, MetaEvent)
-            }
-            EventTypes::Package => {
-                event_push!(package_event, PackageEvent)
-            }
-            EventTypes::Ping => {
-                event_push!(ping_event, PingEvent)
-            }
-            EventTypes::Sponsorship => {
-                event_push!(sponsorship_event, SponsorshipEvent)
-            }
-        }
-    };
-}
-
 impl<T> Client<T>
 where
     T: Debug + EventHandler<GitHubClient = Client<T>> + Send + Sync + 'static,
 {
-    #[cfg(all(target_family = "wasm", feature = "workers"))]
-    pub async fn handle(self, mut req: worker::Request) -> Option<(&'static str, u16)> {
-        let self_arc = Arc::new(self);
-        let thread_self = self_arc.clone();
-        let thread_self_2 = self_arc.clone();
+    /// Parses a webhook payload, returning a future that passes it to the
+    /// matching [`EventHandler`] method.
+    ///
+    /// Returns `Err` if the payload could not be parsed, in which case the
+    /// future calls [`EventHandler::on_parse_error`] instead.
+    fn dispatch(
+        self: &Arc<Self>,
+        ctx: &WebhookContext,
+    ) -> Result<BoxFuture<Command<T::Message>>, DispatchError<T::Message>> {
+        let res = match EventTypes::from_str(&ctx.event_type) {
+            Ok(ev) => WebhookEvent::parse(ev, &ctx.raw_body).map(|event| {
+                let client = self.clone();
+                let ctx = ctx.clone();
+
+                Box::pin(async move { client.handler.on_event(client.clone(), ctx, event).await }) as _
+            }),
+            Err(_) => {
+                let payload = serde_json::from_slice::<Value>(&ctx.raw_body);
+                let client = self.clone();
+                let ctx = ctx.clone();
+
+                payload.map(|payload| {
+                    Box::pin(async move {
+                        client
+                            .handler
+                            .unknown_event_with_context(client.clone(), ctx, payload)
+                            .await
+                    }) as _
+                })
+            }
+        };
 
-        #[cfg(debug_assertions)]
-        worker::console_log!("{:#?}", &req.headers().get(GITHUB_EVENT_HEADER));
+        res.map_err(|e| {
+            let client = self.clone();
+            let ctx = ctx.clone();
 
-        let ev = EventTypes::from_str(req.headers().get(GITHUB_EVENT_HEADER).unwrap().unwrap().as_str())
-            .expect("Failed to parse headers");
+            Box::pin(async move { client.handler.on_parse_error(&ctx.event_type, &ctx.raw_body, e).await }) as _
+        })
+    }
 
+    /// Checks the headers of a delivery and parses its payload. `header` is
+    /// used to look up the value of a header.
+    ///
+    /// The returned future, if any, runs the [`EventHandler`] and has to be
+    /// polled even if the payload was rejected.
+    fn deliver(
+        self: &Arc<Self>,
+        header: impl Fn(&str) -> Option<String>,
+        body: &[u8],
+        received_at: SystemTime,
+    ) -> (WebhookStatus, Option<BoxFuture<Command<T::Message>>>) {
         #[cfg(feature = "secrets")]
-        let secret = self_arc.handler.listener_secret();
+        {
+            let secrets = self.handler.listener_secrets();
+
+            // The legacy SHA-1 signature is only checked if there is no SHA-256 one
+            let valid = match (header(GITHUB_SIGNATURE_256_HEADER), header(GITHUB_SIGNATURE_HEADER)) {
+                (Some(signature), _) => secrets.verify_sha256(body, &signature),
+                (None, Some(signature)) => secrets.verify_sha1(body, &signature),
+                (None, None) => return (WebhookStatus::MissingSignature, None),
+            };
 
-        #[cfg(feature = "secrets")]
-        let body = req.text().await.unwrap();
+            if !valid {
+                return (WebhookStatus::InvalidSignature, None);
+            }
+        }
 
-        #[cfg(feature = "secrets")]
-        match req.headers().get(GITHUB_SIGNATURE_HEADER).unwrap() {
-            Some(hash) => {
-                if !secret.is_empty() {
-                    let hash = hex::decode(hash.replace("sha256=", "").trim()).unwrap();
+        let ctx = match WebhookContext::from_headers(header, body, received_at) {
+            Some(ctx) => ctx,
+            None => return (WebhookStatus::InvalidPayload, None),
+        };
 
-                    let mut mac = HmacSha256::new_from_slice(secret).unwrap();
-                    mac.update(body.as_bytes());
+        let fut = match self.dispatch(&ctx) {
+            Ok(fut) => fut,
+            Err(fut) => return (WebhookStatus::InvalidPayload, Some(fut)),
+        };
 
-                    if mac.verify_slice(&hash[..]).is_err() {
-                        return Some(("Signatures don't match!", 400));
-                    }
-                }
-            }
-            None => return Some(("Missing X-Hub-Signature-256 header!", 401)),
-        }
+        let client = self.clone();
 
-        macro_rules! event_push {
-            ($f:ident, $t:ty) => {
-                thread_self
-                    .event_handler()
-                    .$f(
-                        thread_self.clone(),
-                        #[cfg(not(feature = "secret"))]
-                        (req).json::<$t>().await.expect("Failed to parse json"),
-                        #[cfg(feature = "secret")]
-                        serde_json::from_str::<$t>(body.as_str()).expect("Failed to parse json"),
-                    )
-                    .await
+        let is_duplicate = match (&self.idempotency_store, &ctx.delivery_id) {
+            (Some(store), Some(delivery_id)) => !store.insert(delivery_id),
+            _ => false,
+        };
+
+        if is_duplicate {
+            let fut = async move {
+                client.handler.on_duplicate_delivery(&ctx).await;
+                Command::none()
             };
+
+            return (WebhookStatus::Duplicate, Some(Box::pin(fut)));
         }
 
-        let user_cmd = event_handle!(ev);
+        let fut = async move {
+            client.handler.on_delivery(&ctx).await;
+            fut.await
+        };
+
+        (WebhookStatus::Accepted, Some(Box::pin(fut)))
+    }
 
-        let mut cmd = user_cmd.into_futures();
+    /// Runs the futures of a [`Command`] concurrently, passing each message to
+    /// [`EventHandler::message`] as soon as it is ready.
+    async fn run_command(&self, cmd: Command<T::Message>) {
+        let mut futures = cmd.into_futures().into_iter().collect::<FuturesUnordered<_>>();
 
-        while let Some(c) = cmd.pop() {
-            thread_self_2.event_handler().message(c.await).await;
+        while let Some(message) = futures.next().await {
+            self.handler.message(message).await;
         }
-
-        None
     }
 
-    #[cfg(feature = "native")]
-    pub async fn start(self) {
+    /// Handles a webhook delivery, returning the body and status code of the
+    /// response.
+    #[cfg(all(target_family = "wasm", feature = "workers"))]
+    pub async fn handle(self, mut req: worker::Request) -> (String, u16) {
         let self_arc = Arc::new(self);
-        let thread_self = self_arc.clone();
-        let thread_self_2 = self_arc.clone();
-        let (tx, mut rx) = mpsc::channel(32);
 
-        #[cfg(feature = "secrets")]
-        let event_type = warp::post()
-            .and(warp::path(self_arc.handler.route()))
-            .and(warp::header::<EventTypes>(GITHUB_EVENT_HEADER))
-            .and(warp::body::content_length_limit(self_arc.max_payload_size)) // 8Kb
-            .and(warp::body::bytes());
+        #[cfg(debug_assertions)]
+        worker::console_log!("{:#?}", &req.headers().get(GITHUB_EVENT_HEADER));
 
-        #[cfg(not(feature = "secrets"))]
-        let event_type = warp::post()
-            .and(warp::path(self_arc.handler.route()))
-            .and(warp::header::<EventTypes>(GITHUB_EVENT_HEADER))
-            .and(warp::body::content_length_limit(self_arc.max_payload_size)) // 8Kb
-            .and(warp::body::json());
+        // `SystemTime::now` isn't supported on wasm
+        let received_at = UNIX_EPOCH + Duration::from_millis(worker::Date::now().as_millis());
+        let headers = req.headers().clone();
+        let header = |name: &str| headers.get(name).ok().flatten();
 
-        #[cfg(feature = "secrets")]
-        let event_type = event_type.and(warp::header::<String>(GITHUB_SIGNATURE_HEADER));
+        let (status, fut) = match req.bytes().await {
+            Ok(body) => self_arc.deliver(header, &body, received_at),
+            Err(_) => (WebhookStatus::InvalidPayload, None),
+        };
 
-        #[cfg(feature = "secrets")]
-        let routes = event_type.map(move |ev: EventTypes, body: Bytes, hash: String| {
-            let secret = thread_self.event_handler().listener_secret();
+        if let Some(fut) = fut {
+            self_arc.run_command(fut.await).await;
+        }
 
-            if !secret.is_empty() {
-                let mut mac = HmacSha256::new_from_slice(secret).unwrap();
-                mac.update(&body[..]);
+        (self_arc.handler.response_body(status), status.status_code())
+    }
 
-                let hash = hex::decode(hash.replace("sha256=", "").trim()).unwrap();
+    /// Starts the webhook listener.
+    ///
+    /// Each accepted delivery is handled in its own task, of which at most
+    /// [`ClientBuilder::max_concurrency`] run at once. Once that limit has
+    /// been reached, new deliveries wait for a task to finish before being
+    /// answered.
+    ///
+    /// [`ClientBuilder::max_concurrency`]: crate::github::ClientBuilder::max_concurrency
+    #[cfg(feature = "native")]
+    pub async fn start(self) {
+        self.start_with_shutdown(futures::future::pending()).await
+    }
 
-                if mac.verify_slice(&hash[..]).is_err() {
-                    return "Invalid hash";
-                }
-            }
+    /// Starts the webhook listener, stopping it once `signal` completes.
+    ///
+    /// After the signal, no new deliveries are accepted, and the listener
+    /// waits for the ones that are still being handled for up to
+    /// [`ClientBuilder::drain_timeout`].
+    ///
+    /// ```rust,ignore
+    /// client.start_with_shutdown(async { tokio::signal::ctrl_c().await.unwrap() }).await;
+    /// ```
+    ///
+    /// [`ClientBuilder::drain_timeout`]: crate::github::ClientBuilder::drain_timeout
+    #[cfg(feature = "native")]
+    pub async fn start_with_shutdown(self, signal: impl Future<Output = ()> + Send + 'static) {
+        self.serve(signal).1.await
+    }
 
-            macro_rules! event_push {
-                ($f:ident, $t:ty) => {
-                    thread_self
-                        .event_handler()
-                        .$f(
-                            thread_self.clone(),
-                            serde_json::from_str::<$t>(String::from_utf8(body.to_vec()).unwrap().as_str())
-                                .expect("Failed to parse json"),
-                        )
-                        .await
-                };
-            }
+    /// Starts the webhook listener in the background. The returned
+    /// [`ListenerHandle`] can be used to get the address the listener is
+    /// bound to, e.g. when using port 0, and to shut it down.
+    ///
+    /// Must be called from within a tokio runtime.
+    #[cfg(feature = "native")]
+    pub fn listen(self) -> ListenerHandle {
+        let (tx, rx) = oneshot::channel();
+        let (local_addr, server) = self.serve(async {
+            let _ = rx.await;
+        });
 
-            let ev_h = async {
-                let user_cmd = event_handle!(ev);
+        ListenerHandle::new(local_addr, tx, tokio::spawn(server))
+    }
 
-                if !user_cmd.is_empty() {
-                    let _ = &tx.send(user_cmd).await;
+    /// Binds the listener, returning the bound address and a future that
+    /// serves deliveries until `signal` completes.
+    #[cfg(feature = "native")]
+    fn serve(
+        self,
+        signal: impl Future<Output = ()> + Send + 'static,
+    ) -> (SocketAddr, impl Future<Output = ()> + Send + 'static) {
+        let self_arc = Arc::new(self);
+        let thread_self = self_arc.clone();
+        let semaphore = Arc::new(Semaphore::new(self_arc.max_concurrency));
+        let drain_semaphore = semaphore.clone();
+
+        let routes = warp::post()
+            .and(warp::path(self_arc.handler.route()))
+            .and(warp::header::headers_cloned())
+            .and(warp::body::content_length_limit(self_arc.max_payload_size)) // 8Kb
+            .and(warp::body::bytes())
+            .and_then(move |headers: HeaderMap, body: Bytes| {
+                let client = thread_self.clone();
+                let semaphore = semaphore.clone();
+
+                async move {
+                    let header = |name: &str| Some(headers.get(name)?.to_str().ok()?.to_owned());
+                    let (status, fut) = client.deliver(header, &body, SystemTime::now());
+
+                    if let Some(fut) = fut {
+                        let permit = semaphore.acquire_owned().await.expect("semaphore is never closed");
+                        let client = client.clone();
+
+                        tokio::spawn(async move {
+                            client.run_command(fut.await).await;
+                            drop(permit);
+                        });
+                    }
+
+                    Ok::<_, Infallible>(reply::with_status(
+                        client.handler.response_body(status),
+                        StatusCode::from_u16(status.status_code()).unwrap(),
+                    ))
                 }
-            };
+            });
 
-            futures::executor::block_on(ev_h);
+        let addr = self_arc.bind_addr();
 
-            // Needed due to trait bounds
-            ""
-        });
+        #[cfg(feature = "tls")]
+        let (local_addr, server) = match &self_arc.tls {
+            Some(tls) => {
+                let (local_addr, server) = warp::serve(routes)
+                    .tls()
+                    .cert_path(&tls.cert_path)
+                    .key_path(&tls.key_path)
+                    .bind_with_graceful_shutdown(addr, signal);
 
-        #[cfg(not(feature = "secrets"))]
-        let routes = event_type.map(move |ev: EventTypes, body: serde_json::Value| {
-            macro_rules! event_push {
-                ($f:ident, $t:ty) => {
-                    thread_self
-                        .event_handler()
-                        .$f(
-                            thread_self.clone(),
-                            serde_json::from_str::<$t>(body.to_string().as_str()).expect("Failed to parse json"),
-                        )
-                        .await
-                };
+                (local_addr, server.boxed())
             }
+            None => {
+                let (local_addr, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, signal);
 
-            let ev_h = async {
-                let user_cmd = event_handle!(ev);
+                (local_addr, server.boxed())
+            }
+        };
 
-                if !user_cmd.is_empty() {
-                    let _ = &tx.send(user_cmd).await;
-                }
-            };
+        #[cfg(not(feature = "tls"))]
+        let (local_addr, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, signal);
 
-            futures::executor::block_on(ev_h);
+        let run = async move {
+            self_arc.handler.on_start(local_addr).await;
 
-            // Needed due to trait bounds
-            ""
-        });
+            server.await;
 
-        let do_cmd_stuff = async {
-            while let Some(cmd) = rx.recv().await {
-                let mut cmd = cmd.into_futures();
+            // Every delivery that is still being handled holds a permit
+            let permits = u32::try_from(self_arc.max_concurrency).unwrap_or(u32::MAX);
+            let _ = tokio::time::timeout(self_arc.drain_timeout, drain_semaphore.acquire_many(permits)).await;
 
-                while let Some(c) = cmd.pop() {
-                    thread_self_2.event_handler().message(c.await).await;
-                }
-            }
+            self_arc.handler.on_shutdown().await;
         };
 
-        futures::join!(
-            warp::serve(routes).run(([127, 0, 0, 1], self_arc.event_handler().listener_port())),
-            do_cmd_stuff
-        );
+        (local_addr, run)
+    }
+
+    /// Gets the address the listener binds to. Defaults to
+    /// `127.0.0.1:{listener_port}`.
+    #[cfg(feature = "native")]
+    pub fn bind_addr(&self) -> SocketAddr {
+        self.bind_addr
+            .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], self.handler.listener_port())))
     }
 
     /// Creates a new [`Client`].
@@ -485,7 +470,13 @@ where
     pub fn new(handler: T, auth: Option<Authorization>, user_agent: Option<String>, payload_size: Option<u64>) -> Self {
         Self {
             handler,
+            idempotency_store: None,
             max_payload_size: payload_size.unwrap_or(1024 * 8192),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            bind_addr: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
             http_client: HttpClient::new(auth, user_agent),
         }
     }
@@ -494,6 +485,7 @@ where
     pub fn new(handler: T, auth: Option<Authorization>, user_agent: Option<String>) -> Self {
         Self {
             handler,
+            idempotency_store: None,
             http_client: HttpClient::new(auth, user_agent),
         }
     }
@@ -503,15 +495,362 @@ where
         self.http_client.set_auth(auth);
         self
     }
+
+    /// Updates the base URL that requests are sent to in the current
+    /// [`Client`] instance.
+    pub fn set_base_url<V: Into<String>>(mut self, base_url: V) -> Self {
+        self.http_client.set_base_url(base_url);
+        self
+    }
+
+    /// Updates the retry policy in the current [`Client`] instance.
+    #[cfg(feature = "native")]
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.http_client.set_retry_policy(retry_policy);
+        self
+    }
+
+    /// Updates the maximum number of webhook deliveries that are handled at
+    /// once in the current [`Client`] instance. Values above
+    /// [`Semaphore::MAX_PERMITS`] are capped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is 0.
+    #[cfg(feature = "native")]
+    pub fn set_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = checked_max_concurrency(max_concurrency);
+        self
+    }
+
+    /// Updates the address the listener binds to in the current [`Client`]
+    /// instance.
+    #[cfg(feature = "native")]
+    pub fn set_bind_addr(mut self, addr: SocketAddr) -> Self {
+        self.bind_addr = Some(addr);
+        self
+    }
+
+    /// Updates how long the listener waits for pending deliveries when
+    /// shutting down in the current [`Client`] instance.
+    #[cfg(feature = "native")]
+    pub fn set_drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
+    /// Updates the TLS configuration of the listener in the current
+    /// [`Client`] instance.
+    #[cfg(feature = "tls")]
+    pub fn set_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Updates the [`IdempotencyStore`] used to skip duplicate deliveries in
+    /// the current [`Client`] instance.
+    pub fn set_idempotency_store<S: IdempotencyStore + 'static>(mut self, store: S) -> Self {
+        self.idempotency_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Updates the rate limit configuration in the current [`Client`]
+    /// instance.
+    pub fn set_rate_limit_config(mut self, config: RateLimitConfig) -> Self {
+        self.http_client.set_rate_limit_config(config);
+        self
+    }
 }
 
 impl Default for Client<DefaultEventHandler> {
     fn default() -> Client<DefaultEventHandler> {
         Client {
             handler: DefaultEventHandler,
+            idempotency_store: None,
             #[cfg(feature = "native")]
             max_payload_size: 1024 * 8192,
+            #[cfg(feature = "native")]
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            #[cfg(feature = "native")]
+            bind_addr: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            #[cfg(feature = "native")]
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
             http_client: HttpClient::new(None, None),
         }
     }
 }
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde_json::Value;
+
+    use github_rest::model::{event_types::RepoEventInfo, repositories::events::PingEvent};
+
+    use super::*;
+    #[cfg(feature = "secrets")]
+    use crate::github::WebhookSecrets;
+    use crate::github::{dispatch_event, webhook::GITHUB_EVENT_HEADER, LruIdempotencyStore};
+
+    #[derive(Debug, Default)]
+    struct Handler {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler for Handler {
+        type Message = ();
+        type GitHubClient = Client<Self>;
+
+        #[cfg(feature = "secrets")]
+        fn listener_secrets(&self) -> WebhookSecrets {
+            WebhookSecrets::insecure_allow_all()
+        }
+
+        async fn on_parse_error(
+            &self,
+            event_type: &str,
+            _raw_body: &[u8],
+            _error: serde_json::Error,
+        ) -> Command<Self::Message> {
+            self.calls.lock().unwrap().push(format!("parse_error {event_type}"));
+            Command::none()
+        }
+
+        async fn unknown_event(
+            &self,
+            _github_client: Arc<Self::GitHubClient>,
+            event_type: &str,
+            payload: Value,
+        ) -> Command<Self::Message> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("unknown {event_type} {payload}"));
+            Command::none()
+        }
+
+        async fn on_event(
+            &self,
+            github_client: Arc<Self::GitHubClient>,
+            ctx: WebhookContext,
+            event: WebhookEvent,
+        ) -> Command<Self::Message> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("event {:?}", event.event_type()));
+            dispatch_event(self, github_client, ctx, event).await
+        }
+
+        async fn on_start(&self, _local_addr: SocketAddr) {
+            self.calls.lock().unwrap().push("start".to_owned());
+        }
+
+        async fn on_shutdown(&self) {
+            self.calls.lock().unwrap().push("shutdown".to_owned());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_errors() {
+        let client = Arc::new(Client::new(Handler::default(), None, None, None));
+        let ping = serde_json::to_vec(&PingEvent {
+            zen: "Design for failure.".to_owned(),
+            hook_id: 1,
+            hook: Value::Null,
+            event_info: RepoEventInfo::default(),
+        })
+        .unwrap();
+
+        for (event_type, body, ok) in [
+            ("ping", &ping[..], true),
+            ("push", b"{\"ref\": 1}", false),
+            ("push", b"not json", false),
+            ("merge_group", b"{\"action\":\"x\"}", true),
+            ("merge_group", b"", false),
+        ] {
+            let ctx = WebhookContext::from_headers(headers(&[("X-GitHub-Event", event_type)]), body, SystemTime::now());
+            let res = client.dispatch(&ctx.unwrap());
+            assert_eq!(res.is_ok(), ok);
+
+            match res {
+                Ok(fut) | Err(fut) => client.run_command(fut.await).await,
+            }
+        }
+
+        assert_eq!(
+            *client.handler.calls.lock().unwrap(),
+            [
+                "event Ping",
+                "parse_error push",
+                "parse_error push",
+                "unknown merge_group {\"action\":\"x\"}",
+                "parse_error merge_group",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_command_concurrently() {
+        let client = Client::new(Handler::default(), None, None, None);
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        // The second future can only finish once the first one has run
+        let cmd = Command::perform_multiple([
+            Command::perform(async move { tx.send(()).unwrap() }, |_| ()),
+            Command::perform(async move { rx.await.unwrap() }, |_| ()),
+        ]);
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), client.run_command(cmd))
+            .await
+            .unwrap();
+    }
+
+    /// Looks up headers in a list of `(name, value)` pairs.
+    fn headers<'a>(headers: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        |name| {
+            headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_deliver() {
+        let client = Arc::new(Client::new(Handler::default(), None, None, None));
+        let status = |h: &[(&str, &str)], body| client.deliver(headers(h), body, SystemTime::now()).0;
+
+        // Any signature is accepted, as the handler allows every delivery
+        let signature = ("X-Hub-Signature-256", "sha256=00");
+
+        assert_eq!(status(&[signature], b"{}"), WebhookStatus::InvalidPayload);
+        assert_eq!(
+            status(&[signature, ("X-GitHub-Event", "push")], b"[]"),
+            WebhookStatus::InvalidPayload
+        );
+        assert_eq!(
+            status(&[signature, ("X-GitHub-Event", "merge_group")], b"{}"),
+            WebhookStatus::Accepted
+        );
+
+        #[cfg(feature = "secrets")]
+        assert_eq!(
+            status(&[("X-GitHub-Event", "merge_group")], b"{}"),
+            WebhookStatus::MissingSignature
+        );
+        #[cfg(feature = "secrets")]
+        assert_eq!(
+            status(
+                &[("X-Hub-Signature", "sha1=00"), ("X-GitHub-Event", "merge_group")],
+                b"{}"
+            ),
+            WebhookStatus::Accepted
+        );
+
+        assert_eq!(WebhookStatus::Accepted.status_code(), 202);
+        assert_eq!(
+            client.handler.response_body(WebhookStatus::InvalidSignature),
+            "Signatures don't match"
+        );
+    }
+
+    #[cfg(feature = "secrets")]
+    #[test]
+    fn test_deprecated_listener_secret() {
+        #[derive(Debug)]
+        struct Legacy;
+
+        #[async_trait]
+        impl EventHandler for Legacy {
+            type Message = ();
+            type GitHubClient = Client<Self>;
+
+            fn listener_secret(&self) -> &'static [u8] {
+                b"It's a Secret to Everybody"
+            }
+        }
+
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+        assert!(Legacy.listener_secrets().verify_sha256(b"Hello, World!", signature));
+    }
+
+    #[tokio::test]
+    async fn test_deduplicate() {
+        let client = Arc::new(
+            Client::new(Handler::default(), None, None, None).set_idempotency_store(LruIdempotencyStore::new(16)),
+        );
+        let base = [("X-Hub-Signature-256", "sha256=00"), ("X-GitHub-Event", "merge_group")];
+        let delivery = ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958");
+
+        for (h, expected) in [
+            (&[base[0], base[1], delivery][..], WebhookStatus::Accepted),
+            (&[base[0], base[1], delivery], WebhookStatus::Duplicate),
+            (&base, WebhookStatus::Accepted),
+            (&base, WebhookStatus::Accepted),
+        ] {
+            let (status, fut) = client.deliver(headers(h), b"{}", SystemTime::now());
+            assert_eq!(status, expected);
+
+            client.run_command(fut.unwrap().await).await;
+        }
+
+        assert_eq!(client.handler.calls.lock().unwrap().len(), 3);
+        assert_eq!(WebhookStatus::Duplicate.status_code(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_listen() {
+        let handler = Handler::default();
+        let calls = handler.calls.clone();
+        let client = Client::new(handler, None, None, None).set_bind_addr(SocketAddr::from(([127, 0, 0, 1], 0)));
+
+        let listener = client.listen();
+        assert_ne!(listener.local_addr().port(), 0);
+
+        let res = reqwest::Client::new()
+            .post(format!("http://{}/payload", listener.local_addr()))
+            .header(GITHUB_EVENT_HEADER, "merge_group")
+            .header("X-Hub-Signature-256", "sha256=00")
+            .body("{}")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), 202);
+        assert_eq!(res.text().await.unwrap(), "Accepted");
+
+        listener.shutdown().await;
+
+        assert_eq!(*calls.lock().unwrap(), ["start", "unknown merge_group {}", "shutdown"]);
+    }
+
+    #[test]
+    fn test_bind_addr() {
+        let client = Client::new(Handler::default(), None, None, None);
+        assert_eq!(client.bind_addr(), SocketAddr::from(([127, 0, 0, 1], 8080)));
+
+        let client = client.set_bind_addr("[::]:3000".parse().unwrap());
+        assert_eq!(client.bind_addr().to_string(), "[::]:3000");
+    }
+
+    #[test]
+    fn test_max_concurrency() {
+        let client = Client::new(Handler::default(), None, None, None).set_max_concurrency(usize::MAX);
+        assert_eq!(client.max_concurrency, Semaphore::MAX_PERMITS);
+
+        let client = crate::github::ClientBuilder::new()
+            .event_handler(Handler::default())
+            .max_concurrency(8)
+            .build()
+            .unwrap();
+        assert_eq!(client.max_concurrency, 8);
+
+        assert!(std::panic::catch_unwind(|| checked_max_concurrency(0)).is_err());
+    }
+}
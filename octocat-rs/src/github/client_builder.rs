@@ -1,10 +1,21 @@
 //! Contains a builder for [`Client`].
 
-use std::{ffi::OsStr, fs::File, io::prelude::*, path::Path};
+#[cfg(feature = "tls")]
+use std::path::PathBuf;
+use std::{ffi::OsStr, fs::File, io::prelude::*, path::Path, sync::Arc};
+#[cfg(feature = "native")]
+use std::{net::SocketAddr, time::Duration};
 
 use anyhow::{Error, Result};
+use github_rest::enterprise_api_url;
+#[cfg(feature = "native")]
+use github_rest::RetryPolicy;
 
-use crate::github::{handler::EventHandler, util::*, Client, DefaultEventHandler};
+#[cfg(feature = "tls")]
+use crate::github::TlsConfig;
+#[cfg(feature = "native")]
+use crate::github::{checked_max_concurrency, DEFAULT_DRAIN_TIMEOUT, DEFAULT_MAX_CONCURRENCY};
+use crate::github::{handler::EventHandler, util::*, Client, DefaultEventHandler, IdempotencyStore, RateLimitConfig};
 
 /// A builder for [`Client`]
 pub struct ClientBuilder<T>
@@ -14,8 +25,21 @@ where
     handler: Option<T>,
     auth: Option<Authorization>,
     user_agent: Option<String>,
+    base_url: Option<String>,
     #[cfg(feature = "native")]
     payload_size: Option<u64>,
+    rate_limit_config: RateLimitConfig,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    #[cfg(feature = "native")]
+    retry_policy: RetryPolicy,
+    #[cfg(feature = "native")]
+    max_concurrency: usize,
+    #[cfg(feature = "native")]
+    bind_addr: Option<SocketAddr>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+    #[cfg(feature = "native")]
+    drain_timeout: Duration,
 }
 
 impl<T> ClientBuilder<T>
@@ -51,6 +75,99 @@ where
         self
     }
 
+    /// Sets the base URL of the API, e.g. to point the client at a local mock
+    /// server. Default: `https://api.github.com`.
+    ///
+    /// For GitHub Enterprise Server, use [`ClientBuilder::enterprise_url`]
+    /// instead.
+    pub fn base_url<V: Into<String>>(mut self, base_url: V) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Points the client at a GitHub Enterprise Server instance, e.g.
+    /// `https://github.example.com`. The `/api/v3` prefix is added if it's
+    /// missing.
+    pub fn enterprise_url<V: AsRef<str>>(self, url: V) -> Self {
+        self.base_url(enterprise_api_url(url.as_ref()))
+    }
+
+    /// Sets how the client behaves once GitHub's rate limit has been
+    /// exhausted. By default, requests wait up to 15 minutes for the rate
+    /// limit to reset.
+    ///
+    /// See also: [`RateLimitConfig`]
+    pub fn rate_limit_config(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limit_config = config;
+        self
+    }
+
+    /// Skips deliveries that have already been handled, using the given
+    /// [`IdempotencyStore`] to keep track of their `X-GitHub-Delivery` IDs.
+    /// Disabled by default.
+    ///
+    /// ```rust,ignore
+    /// let client = ClientBuilder::new()
+    ///     .event_handler(Handler {})
+    ///     .idempotency_store(LruIdempotencyStore::default())
+    ///     .build()?;
+    /// ```
+    pub fn idempotency_store<S: IdempotencyStore + 'static>(mut self, store: S) -> Self {
+        self.idempotency_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] used for requests that fail for transient
+    /// reasons. By default, idempotent requests are attempted up to 3 times.
+    #[cfg(feature = "native")]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the maximum number of webhook deliveries that are handled at once.
+    /// Default: [`DEFAULT_MAX_CONCURRENCY`]. Values above
+    /// [`Semaphore::MAX_PERMITS`] are capped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is 0.
+    ///
+    /// [`Semaphore::MAX_PERMITS`]: tokio::sync::Semaphore::MAX_PERMITS
+    #[cfg(feature = "native")]
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = checked_max_concurrency(max_concurrency);
+        self
+    }
+
+    /// Sets the address the listener binds to, e.g. `0.0.0.0:8080` to accept
+    /// connections from outside of a container, or `[::]:8080` for IPv6.
+    /// Overrides [`EventHandler::listener_port`]. Default:
+    /// `127.0.0.1:{listener_port}`.
+    #[cfg(feature = "native")]
+    pub fn bind_addr<A: Into<SocketAddr>>(mut self, addr: A) -> Self {
+        self.bind_addr = Some(addr.into());
+        self
+    }
+
+    /// Sets how long the listener waits for pending deliveries to be handled
+    /// when shutting down. Default: [`DEFAULT_DRAIN_TIMEOUT`].
+    ///
+    /// See also: [`Client::start_with_shutdown`]
+    #[cfg(feature = "native")]
+    pub fn drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
+    /// Serves the listener over HTTPS, using the PEM encoded certificate chain
+    /// and private key at the given paths.
+    #[cfg(feature = "tls")]
+    pub fn tls<P: Into<PathBuf>>(mut self, cert_path: P, key_path: P) -> Self {
+        self.tls = Some(TlsConfig::new(cert_path, key_path));
+        self
+    }
+
     /// Adds an [`Authorization`] instance to the current builder using input
     /// from a file.
     pub fn credentials_file<P: AsRef<Path>>(self, file: P) -> Self {
@@ -81,6 +198,19 @@ where
         self.set_auth(Some(auth))
     }
 
+    /// Adds an [`Authorization::Bearer`] instance to the current builder
+    /// using a token from an environment variable.
+    pub fn bearer_token_env_var<K: AsRef<OsStr>>(self, token_var: K) -> Self {
+        let token = std::env::var(token_var).expect("token not set!");
+
+        self.set_auth(Some(Authorization::Bearer(token)))
+    }
+
+    /// Adds an [`Authorization::Bearer`] instance to the current builder.
+    pub fn bearer_token<V: Into<String>>(self, token: V) -> Self {
+        self.set_auth(Some(Authorization::Bearer(token.into())))
+    }
+
     /// Adds an [`Authorization`] instance to the current builder.
     pub fn personal_auth<V: Into<String>>(self, username: V, token: V) -> Self {
         let auth = Authorization::PersonalToken {
@@ -91,6 +221,30 @@ where
         self.set_auth(Some(auth))
     }
 
+    /// Authenticates as a GitHub App. See [`Authorization::App`].
+    #[cfg(feature = "apps")]
+    pub fn app_auth<V: Into<String>>(self, app_id: u64, private_key_pem: V) -> Self {
+        let auth = Authorization::App {
+            app_id,
+            private_key_pem: private_key_pem.into(),
+        };
+
+        self.set_auth(Some(auth))
+    }
+
+    /// Authenticates as an installation of a GitHub App. See
+    /// [`Authorization::Installation`].
+    #[cfg(feature = "apps")]
+    pub fn installation_auth<V: Into<String>>(self, app_id: u64, private_key_pem: V, installation_id: u64) -> Self {
+        let auth = Authorization::Installation {
+            app_id,
+            private_key_pem: private_key_pem.into(),
+            installation_id,
+        };
+
+        self.set_auth(Some(auth))
+    }
+
     fn set_auth(mut self, auth: Option<Authorization>) -> Self {
         self.auth = auth;
         self
@@ -103,13 +257,44 @@ where
             return Err(Error::from(BuildError::NoHandler));
         }
 
-        Ok(Client::new(
+        let client = Client::new(
             self.handler.unwrap(),
             self.auth,
             self.user_agent,
             #[cfg(feature = "native")]
             self.payload_size,
-        ))
+        )
+        .set_rate_limit_config(self.rate_limit_config);
+
+        #[cfg(feature = "native")]
+        let client = client
+            .set_retry_policy(self.retry_policy)
+            .set_max_concurrency(self.max_concurrency)
+            .set_drain_timeout(self.drain_timeout);
+
+        let client = match self.base_url {
+            Some(base_url) => client.set_base_url(base_url),
+            None => client,
+        };
+
+        let client = match self.idempotency_store {
+            Some(store) => client.set_idempotency_store(store),
+            None => client,
+        };
+
+        #[cfg(feature = "native")]
+        let client = match self.bind_addr {
+            Some(addr) => client.set_bind_addr(addr),
+            None => client,
+        };
+
+        #[cfg(feature = "tls")]
+        let client = match self.tls {
+            Some(tls) => client.set_tls(tls),
+            None => client,
+        };
+
+        Ok(client)
     }
 }
 
@@ -123,13 +308,44 @@ impl ClientBuilder<DefaultEventHandler> {
     ///
     /// Requires T to be set to [`DefaultEventHandler`].
     pub fn build_no_handler(self) -> Result<Client<DefaultEventHandler>> {
-        Ok(Client::new(
+        let client = Client::new(
             DefaultEventHandler::new(),
             self.auth,
             self.user_agent,
             #[cfg(feature = "native")]
             self.payload_size,
-        ))
+        )
+        .set_rate_limit_config(self.rate_limit_config);
+
+        #[cfg(feature = "native")]
+        let client = client
+            .set_retry_policy(self.retry_policy)
+            .set_max_concurrency(self.max_concurrency)
+            .set_drain_timeout(self.drain_timeout);
+
+        let client = match self.base_url {
+            Some(base_url) => client.set_base_url(base_url),
+            None => client,
+        };
+
+        let client = match self.idempotency_store {
+            Some(store) => client.set_idempotency_store(store),
+            None => client,
+        };
+
+        #[cfg(feature = "native")]
+        let client = match self.bind_addr {
+            Some(addr) => client.set_bind_addr(addr),
+            None => client,
+        };
+
+        #[cfg(feature = "tls")]
+        let client = match self.tls {
+            Some(tls) => client.set_tls(tls),
+            None => client,
+        };
+
+        Ok(client)
     }
 }
 
@@ -144,6 +360,19 @@ where
             #[cfg(feature = "native")]
             payload_size: None,
             user_agent: None,
+            base_url: None,
+            rate_limit_config: RateLimitConfig::default(),
+            idempotency_store: None,
+            #[cfg(feature = "native")]
+            retry_policy: RetryPolicy::default(),
+            #[cfg(feature = "native")]
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            #[cfg(feature = "native")]
+            bind_addr: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            #[cfg(feature = "native")]
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
         }
     }
 }
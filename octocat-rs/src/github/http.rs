@@ -1,7 +1,11 @@
 //! Contains [`HttpClient`].
 
 #[cfg(all(target_family = "wasm", feature = "workers"))]
-use std::{io::Write, num::NonZeroU16};
+use std::io::Write;
+
+#[cfg(feature = "native")]
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 use async_trait::async_trait;
 #[cfg(all(target_family = "wasm", feature = "workers"))]
@@ -18,12 +22,19 @@ use tokio::time::Duration;
 #[cfg(all(target_family = "wasm", feature = "workers"))]
 use worker::{Fetch, Headers, Method, Request, RequestInit};
 
+#[cfg(feature = "native")]
+use github_rest::RetryPolicy;
 use github_rest::{
     methods::prelude::{EndPoints, Methods},
-    GithubRestError,
+    GithubRestError, Requester, Response, GITHUB_API_URL,
 };
 
-use crate::github::Authorization;
+#[cfg(feature = "apps")]
+use crate::github::app::{app_jwt, InstallationToken};
+use crate::github::{
+    rate_limit::{unix_time, RateLimitTracker},
+    Authorization, RateLimitConfig,
+};
 
 const USER_AGENT_PARSE_ERROR: &str = "HttpClient: Parsing user agent";
 const ACCEPT_HEADER_PARSE_ERROR: &str = "HttpClient: Parsing accept header";
@@ -39,6 +50,13 @@ pub struct HttpClient {
     #[cfg(all(target_family = "wasm", feature = "workers"))]
     user_agent: Option<String>,
     auth: Option<Authorization>,
+    base_url: String,
+    rate_limit_config: RateLimitConfig,
+    rate_limits: Mutex<RateLimitTracker>,
+    #[cfg(feature = "native")]
+    retry_policy: RetryPolicy,
+    #[cfg(feature = "apps")]
+    installation_token: Mutex<Option<InstallationToken>>,
 }
 
 impl HttpClient {
@@ -68,6 +86,12 @@ impl HttpClient {
                 .build()
                 .unwrap(),
             auth,
+            base_url: GITHUB_API_URL.to_owned(),
+            rate_limit_config: RateLimitConfig::default(),
+            rate_limits: Mutex::default(),
+            retry_policy: RetryPolicy::default(),
+            #[cfg(feature = "apps")]
+            installation_token: Mutex::default(),
         }
     }
 
@@ -79,12 +103,45 @@ impl HttpClient {
     /// Creates a new `HttpClient`.
     #[cfg(all(target_family = "wasm", feature = "workers"))]
     pub fn new(auth: Option<Authorization>, user_agent: Option<String>) -> Self {
-        HttpClient { user_agent, auth }
+        HttpClient {
+            user_agent,
+            auth,
+            base_url: GITHUB_API_URL.to_owned(),
+            rate_limit_config: RateLimitConfig::default(),
+            rate_limits: Mutex::default(),
+            #[cfg(feature = "apps")]
+            installation_token: Mutex::default(),
+        }
     }
 
     /// Updates the authorization used by the current client.
     pub fn set_auth(&mut self, auth: Authorization) {
         self.auth = Some(auth);
+
+        #[cfg(feature = "apps")]
+        self.installation_token.get_mut().unwrap().take();
+    }
+
+    /// Sets the base URL that requests are sent to. Default:
+    /// `https://api.github.com`.
+    ///
+    /// For GitHub Enterprise Server, see [`enterprise_api_url`].
+    ///
+    /// [`enterprise_api_url`]: github_rest::enterprise_api_url
+    pub fn set_base_url<V: Into<String>>(&mut self, base_url: V) {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+    }
+
+    /// Sets how the current client behaves once a rate limit has been
+    /// exhausted.
+    pub fn set_rate_limit_config(&mut self, config: RateLimitConfig) {
+        self.rate_limit_config = config;
+    }
+
+    /// Sets the [`RetryPolicy`] used by the current client.
+    #[cfg(feature = "native")]
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
     }
 
     /// Set the user agent used by the current client.
@@ -114,37 +171,53 @@ impl HttpClient {
         self.user_agent = Some(user_agent);
     }
 
-    #[cfg(feature = "native")]
-    fn http_auth(&self, req: RequestBuilder) -> RequestBuilder {
-        if let Some(auth) = &self.auth {
-            match auth {
-                Authorization::PersonalToken { username, token } => req.basic_auth(username, Some(token)),
-            }
-        } else {
-            req
+    /// Holds back a request to `path` until the rate limit allows it to be
+    /// sent, or returns [`GithubRestError::RateLimited`] if that would take
+    /// too long.
+    async fn wait_for_rate_limit(&self, path: &str) -> Result<(), GithubRestError> {
+        let blocked = match self.rate_limits.lock().unwrap().check(path, unix_time()) {
+            Some(blocked) => blocked,
+            None => return Ok(()),
+        };
+
+        #[cfg(feature = "native")]
+        if self.rate_limit_config.wait && blocked.wait() <= self.rate_limit_config.max_wait {
+            tokio::time::sleep(blocked.wait()).await;
+            return Ok(());
         }
+
+        Err(blocked.into_error())
     }
-}
 
-#[async_trait]
-impl github_rest::Requester for HttpClient {
-    /// Returns the API response as a [`String`].
+    /// Sends a request once the rate limit allows it, without checking the
+    /// status code of the response.
     #[cfg(feature = "native")]
-    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    async fn send<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<reqwest::Response, GithubRestError>
     where
         T: Serialize + ?Sized + Send + Sync,
-        V: Into<Self::Body> + Send,
+        V: Into<reqwest::Body> + Send,
     {
-        let req = {
-            let path = format!("https://api.github.com{}", url.path());
+        self.wait_for_rate_limit(path).await?;
 
-            let mut req = self.http_auth(match url.method() {
-                Methods::Get => self.client.get(path),
-                Methods::Post => self.client.post(path),
-                Methods::Put => self.client.put(path),
-                Methods::Patch => self.client.patch(path),
-                Methods::Delete => self.client.delete(path),
-            });
+        let req = {
+            let path = format!("{}{path}", self.base_url);
+
+            let mut req = self
+                .http_auth(match method {
+                    Methods::Get => self.client.get(path),
+                    Methods::Post => self.client.post(path),
+                    Methods::Put => self.client.put(path),
+                    Methods::Patch => self.client.patch(path),
+                    Methods::Delete => self.client.delete(path),
+                })
+                .await?;
 
             if let Some(query) = query {
                 req = req.query(query);
@@ -153,35 +226,35 @@ impl github_rest::Requester for HttpClient {
             if let Some(body) = body {
                 req = req.body(body);
             }
-            
-            req
-        };
-
-        let res = req.send().await?;
 
-        match res.status().as_u16() {
-            200..=299 => {}
-            401 => {
-                return Err(GithubRestError::NotAuthorized(res.text().await?));
+            for (name, value) in headers {
+                req = req.header(*name, *value);
             }
-            _ => {
-                return Err(GithubRestError::ResponseError(res.status(), res.text().await?));
-            }
-        }
 
-        let txt = res.text().await?;
+            req
+        };
 
-        Ok(txt)
+        Ok(self.retry_policy.send(&method, req).await?)
     }
 
-    /// Returns the API response as a [`String`].
+    /// Sends a request once the rate limit allows it, without checking the
+    /// status code of the response.
     #[cfg(all(target_family = "wasm", feature = "workers"))]
-    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    async fn send<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+        extra_headers: &[(&str, &str)],
+    ) -> Result<worker::Response, GithubRestError>
     where
         T: Serialize + ?Sized + Send + Sync,
-        V: Into<Self::Body> + Send,
+        V: Into<<Self as Requester>::Body> + Send,
     {
-        let mut path = format!("https://api.github.com{}", url.path());
+        self.wait_for_rate_limit(path).await?;
+
+        let mut path = format!("{}{path}", self.base_url);
 
         if let Some(q) = query {
             path.push_str(serde_urlencoded::to_string(q).expect("Invalid query").as_str());
@@ -212,9 +285,33 @@ impl github_rest::Requester for HttpClient {
                                 .append(
                                     "authorization",
                                     std::str::from_utf8(&header_value).expect("Failed to parse header value"),
-                                    )
+                                )
                                 .unwrap();
                         }
+                        Authorization::Bearer(token) => {
+                            headers.append("authorization", &format!("Bearer {token}"))?;
+                        }
+                        #[cfg(feature = "apps")]
+                        Authorization::App {
+                            app_id,
+                            private_key_pem,
+                        } => {
+                            let jwt = app_jwt(*app_id, private_key_pem, unix_time())?;
+
+                            headers.append("authorization", &format!("Bearer {jwt}"))?;
+                        }
+                        #[cfg(feature = "apps")]
+                        Authorization::Installation {
+                            app_id,
+                            private_key_pem,
+                            installation_id,
+                        } => {
+                            let token = self
+                                .installation_token(*app_id, private_key_pem, *installation_id)
+                                .await?;
+
+                            headers.append("authorization", &format!("Bearer {token}"))?;
+                        }
                     }
                 }
 
@@ -222,13 +319,17 @@ impl github_rest::Requester for HttpClient {
                     headers.append("user-agent", ua).expect(USER_AGENT_PARSE_ERROR);
                 }
 
+                for (name, value) in extra_headers {
+                    headers.append(name, value)?;
+                }
+
                 headers
             };
 
             let init = {
                 let mut init = RequestInit::new();
-                // I don't want to know. To future generations: I am sorry. 
-                init.with_method(BadWrapper::new(url.method()).into());
+                // I don't want to know. To future generations: I am sorry.
+                init.with_method(BadWrapper::new(method).into());
 
                 init.with_headers(headers);
 
@@ -241,25 +342,298 @@ impl github_rest::Requester for HttpClient {
 
             let req = Request::new_with_init(path.as_str(), &init)?;
 
-            let mut res = Fetch::Request(req).send().await?;
+            Ok(Fetch::Request(req).send().await?)
+        })
+    }
 
-            match res.status_code() {
-                200..=299 => {}
-                401 => {
-                    return Err(GithubRestError::NotAuthorized(res.text().await?));
-                }
-                _ => {
-                    return Err(GithubRestError::ResponseError(
-                        NonZeroU16::new(res.status_code()).unwrap(),
-                        res.text().await?,
-                    ));
-                }
-            }
+    /// Reads the body of `res` as text, updating the rate limits and
+    /// checking its status code.
+    #[cfg(feature = "native")]
+    async fn text_response(&self, res: reqwest::Response) -> Result<Response<String>, GithubRestError> {
+        let (status, headers) = (res.status().as_u16(), response_headers(&res));
+
+        let res = Response {
+            body: res.text().await?,
+            status,
+            headers,
+        };
+
+        self.rate_limits.lock().unwrap().update(&res, unix_time());
+
+        res.error_for_status()
+    }
+
+    /// Reads the body of `res` as text, updating the rate limits and
+    /// checking its status code.
+    #[cfg(all(target_family = "wasm", feature = "workers"))]
+    async fn text_response(&self, mut res: worker::Response) -> Result<Response<String>, GithubRestError> {
+        let headers = res.headers().entries().collect();
+
+        let res = Response {
+            body: res.text().await?,
+            status: res.status_code(),
+            headers,
+        };
+
+        self.rate_limits.lock().unwrap().update(&res, unix_time());
 
-            Ok(res.text().await?)
+        res.error_for_status()
+    }
+
+    #[cfg(feature = "native")]
+    async fn http_auth(&self, req: RequestBuilder) -> Result<RequestBuilder, GithubRestError> {
+        Ok(if let Some(auth) = &self.auth {
+            match auth {
+                Authorization::PersonalToken { username, token } => req.basic_auth(username, Some(token)),
+                Authorization::Bearer(token) => req.bearer_auth(token),
+                #[cfg(feature = "apps")]
+                Authorization::App {
+                    app_id,
+                    private_key_pem,
+                } => req.bearer_auth(app_jwt(*app_id, private_key_pem, unix_time())?),
+                #[cfg(feature = "apps")]
+                Authorization::Installation {
+                    app_id,
+                    private_key_pem,
+                    installation_id,
+                } => req.bearer_auth(
+                    self.installation_token(*app_id, private_key_pem, *installation_id)
+                        .await?,
+                ),
+            }
+        } else {
+            req
         })
     }
 
+    /// Returns a cached installation access token, requesting a new one if it
+    /// is about to expire.
+    #[cfg(feature = "apps")]
+    async fn installation_token(
+        &self,
+        app_id: u64,
+        private_key_pem: &str,
+        installation_id: u64,
+    ) -> Result<String, GithubRestError> {
+        if let Some(token) = &*self.installation_token.lock().unwrap() {
+            if !token.needs_refresh(unix_time()) {
+                return Ok(token.token.clone());
+            }
+        }
+
+        let jwt = app_jwt(app_id, private_key_pem, unix_time())?;
+        let url = EndPoints::PostAppInstallationsinstallationIdAccessTokens(installation_id.to_string());
+        let token = self.fetch_installation_token(&jwt, url).await?;
+
+        self.installation_token.lock().unwrap().replace(token.clone());
+
+        Ok(token.token)
+    }
+
+    #[cfg(all(feature = "apps", feature = "native"))]
+    async fn fetch_installation_token(&self, jwt: &str, url: EndPoints) -> Result<InstallationToken, GithubRestError> {
+        let res = self
+            .client
+            .post(format!("{}{}", self.base_url, url.path()))
+            .bearer_auth(jwt)
+            .send()
+            .await?;
+
+        let res = Response {
+            status: res.status().as_u16(),
+            body: res.text().await?,
+            headers: Default::default(),
+        }
+        .error_for_status()?;
+
+        Ok(serde_json::from_str(&res.body)?)
+    }
+
+    #[cfg(all(feature = "apps", target_family = "wasm", feature = "workers"))]
+    async fn fetch_installation_token(&self, jwt: &str, url: EndPoints) -> Result<InstallationToken, GithubRestError> {
+        let mut headers = Headers::new();
+
+        headers
+            .append("accept", "application/vnd.github.v3+json")
+            .expect(ACCEPT_HEADER_PARSE_ERROR);
+        headers.append("authorization", &format!("Bearer {jwt}"))?;
+
+        if let Some(ua) = &self.user_agent {
+            headers.append("user-agent", ua).expect(USER_AGENT_PARSE_ERROR);
+        }
+
+        let mut init = RequestInit::new();
+        init.with_method(Method::Post).with_headers(headers);
+
+        let req = Request::new_with_init(&format!("{}{}", self.base_url, url.path()), &init)?;
+        let mut res = Fetch::Request(req).send().await?;
+
+        let res = Response {
+            status: res.status_code(),
+            body: res.text().await?,
+            headers: Default::default(),
+        }
+        .error_for_status()?;
+
+        Ok(serde_json::from_str(&res.body)?)
+    }
+}
+
+#[cfg(feature = "native")]
+fn response_headers(res: &reqwest::Response) -> HashMap<String, String> {
+    res.headers()
+        .iter()
+        .filter_map(|(k, v)| Some((k.as_str().to_owned(), v.to_str().ok()?.to_owned())))
+        .collect()
+}
+
+#[async_trait]
+impl Requester for HttpClient {
+    /// Returns the API response as a [`String`].
+    async fn raw_req<T, V>(&self, url: EndPoints, query: Option<&T>, body: Option<V>) -> Result<String, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        Ok(self.raw_req_with_meta(url, query, body).await?.body)
+    }
+
+    /// Returns the API response as a [`String`], along with its headers.
+    async fn raw_req_with_meta<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        self.raw_req_with_headers(url, query, body, &[]).await
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but also sends the given
+    /// request headers.
+    #[cfg(feature = "native")]
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        let res = self.send(url.method(), &url.path(), query, body, headers).await?;
+
+        self.text_response(res).await
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but returns the raw bytes of
+    /// the response.
+    #[cfg(feature = "native")]
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+    {
+        let res = self
+            .send::<T, String>(url.method(), &url.path(), query, None, &[])
+            .await?;
+        let (status, headers) = (res.status().as_u16(), response_headers(&res));
+
+        let res = Response {
+            body: res.bytes().await?.to_vec(),
+            status,
+            headers,
+        };
+
+        self.rate_limits.lock().unwrap().update(&res, unix_time());
+
+        res.error_for_status()
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but sends the request to
+    /// an arbitrary path.
+    #[cfg(feature = "native")]
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        let res = self.send(method, path, query, body, &[]).await?;
+
+        self.text_response(res).await
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but also sends the given
+    /// request headers.
+    #[cfg(all(target_family = "wasm", feature = "workers"))]
+    async fn raw_req_with_headers<T, V>(
+        &self,
+        url: EndPoints,
+        query: Option<&T>,
+        body: Option<V>,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        let res = self.send(url.method(), &url.path(), query, body, headers).await?;
+
+        self.text_response(res).await
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but returns the raw bytes of
+    /// the response.
+    #[cfg(all(target_family = "wasm", feature = "workers"))]
+    async fn raw_req_bytes<T>(&self, url: EndPoints, query: Option<&T>) -> Result<Response<Vec<u8>>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+    {
+        let mut res = self
+            .send::<T, String>(url.method(), &url.path(), query, None, &[])
+            .await?;
+        let headers = res.headers().entries().collect();
+
+        let res = Response {
+            body: res.bytes().await?,
+            status: res.status_code(),
+            headers,
+        };
+
+        self.rate_limits.lock().unwrap().update(&res, unix_time());
+
+        res.error_for_status()
+    }
+
+    /// Same as [`HttpClient::raw_req_with_meta`], but sends the request to
+    /// an arbitrary path.
+    #[cfg(all(target_family = "wasm", feature = "workers"))]
+    async fn raw_req_path<T, V>(
+        &self,
+        method: Methods,
+        path: &str,
+        query: Option<&T>,
+        body: Option<V>,
+    ) -> Result<Response<String>, GithubRestError>
+    where
+        T: Serialize + ?Sized + Send + Sync,
+        V: Into<Self::Body> + Send,
+    {
+        let res = self.send(method, path, query, body, &[]).await?;
+
+        self.text_response(res).await
+    }
+
     /// A function for performing HTTP requests utilizing the [`EndPoints`]
     /// enum.
     ///
@@ -296,7 +670,7 @@ impl github_rest::Requester for HttpClient {
     }
 }
 
-/// I would like to apologize to the world for this crime against nature. 
+/// I would like to apologize to the world for this crime against nature.
 #[cfg(all(target_family = "wasm", feature = "workers"))]
 struct BadWrapper<T> {
     pub(crate) inner: T,
@@ -0,0 +1,131 @@
+//! Contains the [`IdempotencyStore`] trait, used by [`Client`] to skip
+//! deliveries that have already been handled, and its default implementation
+//! ([`LruIdempotencyStore`]).
+//!
+//! [`Client`]: crate::github::Client
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+/// Keeps track of the `X-GitHub-Delivery` IDs of deliveries that have already
+/// been handled.
+///
+/// GitHub retries failed deliveries and allows users to redeliver them by
+/// hand. With a store configured, [`Client`] answers such deliveries with
+/// [`WebhookStatus::Duplicate`] and calls
+/// [`EventHandler::on_duplicate_delivery`] instead of the event's method.
+///
+/// [`Client`]: crate::github::Client
+/// [`WebhookStatus::Duplicate`]: crate::github::WebhookStatus::Duplicate
+/// [`EventHandler::on_duplicate_delivery`]: crate::github::handler::EventHandler::on_duplicate_delivery
+pub trait IdempotencyStore: Send + Sync {
+    /// Records a delivery ID. Returns `false` if it was already recorded.
+    fn insert(&self, delivery_id: &str) -> bool;
+}
+
+impl<S: IdempotencyStore + ?Sized> IdempotencyStore for Arc<S> {
+    fn insert(&self, delivery_id: &str) -> bool {
+        (**self).insert(delivery_id)
+    }
+}
+
+/// An [`IdempotencyStore`] that keeps the most recently seen delivery IDs in
+/// memory, forgetting the least recently seen ones once `capacity` is
+/// exceeded.
+#[derive(Debug)]
+pub struct LruIdempotencyStore {
+    capacity: usize,
+    inner: Mutex<LruInner>,
+}
+
+#[derive(Debug, Default)]
+struct LruInner {
+    counter: u64,
+    /// Delivery ID -> when it was last seen
+    last_seen: HashMap<String, u64>,
+    /// When a delivery was last seen -> delivery ID
+    order: BTreeMap<u64, String>,
+}
+
+impl LruIdempotencyStore {
+    /// Creates a new [`LruIdempotencyStore`] that remembers up to `capacity`
+    /// deliveries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(LruInner::default()),
+        }
+    }
+
+    /// Gets the number of delivery IDs currently stored.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().last_seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for LruIdempotencyStore {
+    /// Remembers up to 10 000 deliveries.
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl IdempotencyStore for LruIdempotencyStore {
+    fn insert(&self, delivery_id: &str) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        let LruInner {
+            counter,
+            last_seen,
+            order,
+        } = &mut *inner;
+
+        *counter += 1;
+
+        if let Some(seen) = last_seen.get_mut(delivery_id) {
+            let id = order.remove(seen).expect("LruIdempotencyStore: Order out of sync");
+            *seen = *counter;
+            order.insert(*counter, id);
+
+            return false;
+        }
+
+        last_seen.insert(delivery_id.to_owned(), *counter);
+        order.insert(*counter, delivery_id.to_owned());
+
+        while last_seen.len() > self.capacity {
+            match order.pop_first() {
+                Some((_, id)) => last_seen.remove(&id),
+                None => break,
+            };
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru() {
+        let store = LruIdempotencyStore::new(2);
+
+        assert!(store.insert("a"));
+        assert!(store.insert("b"));
+        assert!(!store.insert("a"));
+
+        // "b" is the least recently seen delivery at this point
+        assert!(store.insert("c"));
+        assert_eq!(store.len(), 2);
+
+        assert!(!store.insert("a"));
+        assert!(store.insert("b"));
+    }
+}
@@ -3,11 +3,29 @@ pub use client_builder::*;
 pub use command::*;
 pub use handler::*;
 pub use http::*;
+pub use idempotency::*;
+#[cfg(feature = "native")]
+pub use oauth::*;
+pub use rate_limit::*;
+pub use router::*;
+#[cfg(feature = "secrets")]
+pub use secrets::*;
 pub use util::*;
+pub use webhook::*;
 
+#[cfg(feature = "apps")]
+mod app;
 pub mod client;
 pub mod client_builder;
 pub mod command;
 pub mod handler;
 pub mod http;
+pub mod idempotency;
+#[cfg(feature = "native")]
+pub mod oauth;
+pub mod rate_limit;
+pub mod router;
+#[cfg(feature = "secrets")]
+pub mod secrets;
 pub mod util;
+pub mod webhook;
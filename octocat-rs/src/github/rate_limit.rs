@@ -0,0 +1,193 @@
+//! Contains [`RateLimitConfig`], used to configure how [`HttpClient`] deals
+//! with GitHub's rate limits.
+//!
+//! [`HttpClient`]: crate::github::HttpClient
+
+use std::{collections::HashMap, time::Duration};
+
+use github_rest::{GithubRestError, Response};
+
+/// Configures how [`HttpClient`] behaves once a rate limit has been exhausted.
+///
+/// [`HttpClient`] keeps track of the `X-RateLimit-Remaining`,
+/// `X-RateLimit-Reset` and `Retry-After` headers sent by GitHub. When the
+/// budget for a request has been used up, the request is held back until the
+/// rate limit resets, as long as that happens within [`max_wait`]. Otherwise
+/// a [`GithubRestError::RateLimited`] error is returned without sending the
+/// request.
+///
+/// [`HttpClient`]: crate::github::HttpClient
+/// [`max_wait`]: RateLimitConfig::max_wait
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Whether requests should wait for the rate limit to reset. Waiting is
+    /// not supported with the `workers` feature. Default: true.
+    pub wait: bool,
+    /// The longest amount of time a request will wait for. Default: 15
+    /// minutes.
+    pub max_wait: Duration,
+}
+
+impl RateLimitConfig {
+    /// Creates a [`RateLimitConfig`] that never waits, returning an error as
+    /// soon as the rate limit has been exhausted.
+    pub fn no_wait() -> Self {
+        Self {
+            wait: false,
+            max_wait: Duration::ZERO,
+        }
+    }
+
+    /// Sets the longest amount of time a request will wait for.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            wait: true,
+            max_wait: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Keeps track of the rate limit status reported by GitHub.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker {
+    /// Remaining requests and reset time per rate limit resource (`core`,
+    /// `search`, ...).
+    resources: HashMap<String, (u64, u64)>,
+    /// No requests should be sent before this time, as requested by a
+    /// `Retry-After` header.
+    retry_after: Option<u64>,
+}
+
+/// A request that is being held back by [`RateLimitTracker`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Blocked {
+    reset_at: Option<u64>,
+    retry_after: Option<u64>,
+}
+
+impl Blocked {
+    /// How long the request should wait for.
+    pub(crate) fn wait(&self) -> Duration {
+        Duration::from_secs(self.retry_after.unwrap_or(0))
+    }
+
+    pub(crate) fn into_error(self) -> GithubRestError {
+        GithubRestError::RateLimited {
+            reset_at: self.reset_at,
+            retry_after: self.retry_after,
+        }
+    }
+}
+
+impl RateLimitTracker {
+    /// Updates the tracker using the headers of a response.
+    pub(crate) fn update<T>(&mut self, res: &Response<T>, now: u64) {
+        if let Some(rate_limit) = res.rate_limit() {
+            let resource = rate_limit.resource.unwrap_or_else(|| "core".to_owned());
+
+            self.resources
+                .insert(resource, (rate_limit.remaining, rate_limit.reset));
+        }
+
+        if let Some(retry_after) = res.retry_after() {
+            self.retry_after = Some(now + retry_after);
+        }
+    }
+
+    /// Checks whether a request to `path` should be held back. Returns `None`
+    /// if it can be sent right away.
+    pub(crate) fn check(&self, path: &str, now: u64) -> Option<Blocked> {
+        let reset_at = self
+            .resources
+            .get(resource(path))
+            .filter(|(remaining, reset)| *remaining == 0 && *reset > now)
+            .map(|(_, reset)| *reset);
+
+        let until = self
+            .retry_after
+            .filter(|until| *until > now)
+            .into_iter()
+            .chain(reset_at)
+            .max()?;
+
+        Some(Blocked {
+            reset_at,
+            retry_after: Some(until - now),
+        })
+    }
+}
+
+/// Gets the rate limit resource that a request to `path` counts against.
+fn resource(path: &str) -> &'static str {
+    if path.starts_with("/search/code") {
+        "code_search"
+    } else if path.starts_with("/search/") {
+        "search"
+    } else if path.starts_with("/graphql") {
+        "graphql"
+    } else {
+        "core"
+    }
+}
+
+/// Gets the current time in UTC epoch seconds.
+#[cfg(feature = "native")]
+pub(crate) fn unix_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Gets the current time in UTC epoch seconds.
+#[cfg(all(target_family = "wasm", feature = "workers"))]
+pub(crate) fn unix_time() -> u64 {
+    worker::Date::now().as_millis() / 1000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker() {
+        let mut tracker = RateLimitTracker::default();
+
+        let exhausted = Response::new(String::new())
+            .with_header("X-RateLimit-Limit", "30")
+            .with_header("X-RateLimit-Remaining", "0")
+            .with_header("X-RateLimit-Reset", "1100")
+            .with_header("X-RateLimit-Resource", "search");
+
+        tracker.update(&exhausted, 1000);
+
+        assert_eq!(tracker.check("/repos/octocat-rs/octocat-rs", 1000), None);
+        assert_eq!(
+            tracker.check("/search/issues", 1000),
+            Some(Blocked {
+                reset_at: Some(1100),
+                retry_after: Some(100)
+            })
+        );
+        assert_eq!(tracker.check("/search/issues", 1100), None);
+
+        let secondary = Response::new(String::new())
+            .with_status(429)
+            .with_header("Retry-After", "60");
+
+        tracker.update(&secondary, 1000);
+
+        assert_eq!(
+            tracker.check("/repos/octocat-rs/octocat-rs", 1030).unwrap().wait(),
+            Duration::from_secs(30)
+        );
+        assert_eq!(tracker.check("/repos/octocat-rs/octocat-rs", 1060), None);
+    }
+}
@@ -0,0 +1,467 @@
+//! Contains [`Router`], an [`EventHandler`] that passes events on to
+//! closures based on their type, action, repository, sender and branch.
+
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use futures::{future, Future};
+use serde_json::Value;
+
+use github_rest::model::event_types::{EventTypes, WebhookEvent, WebhookPayload};
+
+#[cfg(feature = "secrets")]
+use crate::github::WebhookSecrets;
+use crate::github::{command::BoxFuture, handler::EventHandler, webhook::WebhookContext, Client, Command};
+
+type RouteFn<M> = Box<dyn Fn(&Arc<Client<Router<M>>>, &WebhookEvent) -> Option<BoxFuture<Command<M>>> + Send + Sync>;
+type HandlerFn<E, M> = Box<dyn Fn(Arc<Client<Router<M>>>, E) -> BoxFuture<Command<M>> + Send + Sync>;
+
+/// The type of account that triggered an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SenderType {
+    User,
+    Bot,
+    Organization,
+}
+
+impl SenderType {
+    /// The value of the `type` field of the sender.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SenderType::User => "User",
+            SenderType::Bot => "Bot",
+            SenderType::Organization => "Organization",
+        }
+    }
+}
+
+/// Conditions that have to be met by the payload of an event, on top of its
+/// type and action.
+#[derive(Debug, Clone, Default)]
+struct Filter {
+    repository: Option<String>,
+    sender_type: Option<SenderType>,
+    branch: Option<String>,
+}
+
+impl Filter {
+    fn is_empty(&self) -> bool {
+        self.repository.is_none() && self.sender_type.is_none() && self.branch.is_none()
+    }
+
+    fn matches(&self, payload: &Value) -> bool {
+        let str_at = |pointer| payload.pointer(pointer).and_then(Value::as_str);
+
+        let repository = self.repository.as_ref().is_none_or(|repository| {
+            str_at("/repository/full_name").is_some_and(|full_name| full_name.eq_ignore_ascii_case(repository))
+        });
+        let sender_type = self
+            .sender_type
+            .is_none_or(|sender_type| str_at("/sender/type") == Some(sender_type.as_str()));
+        let branch = self
+            .branch
+            .as_ref()
+            .is_none_or(|branch| branch_of(payload) == Some(branch.trim_start_matches("refs/heads/")));
+
+        repository && sender_type && branch
+    }
+}
+
+/// Gets the branch an event refers to, e.g. the branch that was pushed to or
+/// the base branch of a pull request.
+fn branch_of(payload: &Value) -> Option<&str> {
+    let str_at = |pointer| payload.pointer(pointer).and_then(Value::as_str);
+
+    if let Some(git_ref) = str_at("/ref") {
+        // Create and delete events send the short name of the ref
+        return match (git_ref.strip_prefix("refs/heads/"), str_at("/ref_type")) {
+            (Some(branch), _) => Some(branch),
+            (None, Some("branch")) => Some(git_ref),
+            _ => None,
+        };
+    }
+
+    [
+        "/pull_request/base/ref",
+        "/check_suite/head_branch",
+        "/check_run/check_suite/head_branch",
+        "/workflow_run/head_branch",
+        "/workflow_job/head_branch",
+    ]
+    .into_iter()
+    .find_map(str_at)
+}
+
+/// A single route of a [`Router`], matching events of type `E`.
+///
+/// ```rust,ignore
+/// let route = Route::new(|client, pr: PullRequestEvent| async move {
+///     // ...
+///     Command::none()
+/// })
+/// .action(PullRequestAction::Opened)
+/// .repository("octocat-rs/octocat-rs")
+/// .sender_type(SenderType::User)
+/// .branch("main");
+/// ```
+pub struct Route<E, M = ()>
+where
+    E: WebhookPayload,
+    M: fmt::Debug + Send + 'static,
+{
+    action: Option<E::Action>,
+    filter: Filter,
+    handler: HandlerFn<E, M>,
+}
+
+impl<E, M> Route<E, M>
+where
+    E: WebhookPayload + Clone + Send + 'static,
+    E::Action: Send + Sync + 'static,
+    M: fmt::Debug + Send + 'static,
+{
+    /// Creates a [`Route`] that matches every event of type `E`.
+    pub fn new<Fut>(handler: impl Fn(Arc<Client<Router<M>>>, E) -> Fut + Send + Sync + 'static) -> Self
+    where
+        Fut: Future<Output = Command<M>> + Send + 'static,
+    {
+        Self {
+            action: None,
+            filter: Filter::default(),
+            handler: Box::new(move |client, event| Box::pin(handler(client, event))),
+        }
+    }
+
+    /// Only matches events triggered by the given action.
+    pub fn action(mut self, action: E::Action) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Only matches events from the repository with the given full name, e.g.
+    /// `octocat-rs/octocat-rs`. The comparison is case-insensitive.
+    pub fn repository<V: Into<String>>(mut self, full_name: V) -> Self {
+        self.filter.repository = Some(full_name.into());
+        self
+    }
+
+    /// Only matches events triggered by the given type of account.
+    pub fn sender_type(mut self, sender_type: SenderType) -> Self {
+        self.filter.sender_type = Some(sender_type);
+        self
+    }
+
+    /// Only matches events that refer to the given branch, i.e. pushes to it,
+    /// pull requests targeting it, or check suites and workflow runs on it.
+    /// Both `main` and `refs/heads/main` are accepted.
+    pub fn branch<V: Into<String>>(mut self, branch: V) -> Self {
+        self.filter.branch = Some(branch.into());
+        self
+    }
+
+    fn into_erased(self) -> ErasedRoute<M> {
+        let Self {
+            action,
+            filter,
+            handler,
+        } = self;
+
+        ErasedRoute {
+            event_type: E::EVENT_TYPE,
+            filter,
+            handler: Box::new(move |client, event| {
+                let payload = E::from_webhook_event(event)?;
+
+                if action.as_ref().is_some_and(|action| payload.action() != Some(action)) {
+                    return None;
+                }
+
+                Some(handler(client.clone(), payload.clone()))
+            }),
+        }
+    }
+}
+
+struct ErasedRoute<M>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    event_type: EventTypes,
+    filter: Filter,
+    handler: RouteFn<M>,
+}
+
+/// An [`EventHandler`] made up of [`Route`]s, for when matching on the type
+/// and action of every event by hand gets tedious.
+///
+/// Every route matching an event is run, concurrently. Events that don't
+/// match any route are ignored.
+///
+/// ```rust,ignore
+/// let router = Router::new()
+///     .on(IssueAction::Opened, |client, issue: IssueEvent| async move {
+///         // ...
+///         Command::none()
+///     })
+///     .add_route(Route::new(on_push).branch("main").sender_type(SenderType::User));
+///
+/// ClientBuilder::new().event_handler(router).build()?.start().await;
+/// ```
+///
+/// Use [`Router::default`] to create a router with a message type other than
+/// `()`.
+pub struct Router<M = ()>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    routes: Vec<ErasedRoute<M>>,
+    message_handler: Option<Box<dyn Fn(M) + Send + Sync>>,
+    #[cfg(feature = "native")]
+    listener_port: u16,
+    #[cfg(feature = "native")]
+    listener_route: &'static str,
+    #[cfg(feature = "secrets")]
+    listener_secrets: Option<WebhookSecrets>,
+}
+
+impl Router {
+    /// Creates a [`Router`] without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<M> Router<M>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    /// Adds a route that matches events of type `E` triggered by the given
+    /// action.
+    pub fn on<E, Fut>(
+        self,
+        action: E::Action,
+        handler: impl Fn(Arc<Client<Self>>, E) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        E: WebhookPayload + Clone + Send + 'static,
+        E::Action: Send + Sync + 'static,
+        Fut: Future<Output = Command<M>> + Send + 'static,
+    {
+        self.add_route(Route::new(handler).action(action))
+    }
+
+    /// Adds a route that matches every event of type `E`.
+    pub fn on_any<E, Fut>(self, handler: impl Fn(Arc<Client<Self>>, E) -> Fut + Send + Sync + 'static) -> Self
+    where
+        E: WebhookPayload + Clone + Send + 'static,
+        E::Action: Send + Sync + 'static,
+        Fut: Future<Output = Command<M>> + Send + 'static,
+    {
+        self.add_route(Route::new(handler))
+    }
+
+    /// Adds a [`Route`], which allows for more filters than [`Router::on`].
+    pub fn add_route<E>(mut self, route: Route<E, M>) -> Self
+    where
+        E: WebhookPayload + Clone + Send + 'static,
+        E::Action: Send + Sync + 'static,
+    {
+        self.routes.push(route.into_erased());
+        self
+    }
+
+    /// Sets the function that receives the messages produced by the
+    /// [`Command`]s of the routes.
+    pub fn on_message(mut self, f: impl Fn(M) + Send + Sync + 'static) -> Self {
+        self.message_handler = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the port used by the webhook listener. Default: `8080`.
+    #[cfg(feature = "native")]
+    pub fn listener_port(mut self, port: u16) -> Self {
+        self.listener_port = port;
+        self
+    }
+
+    /// Sets the route at which the listener receives payloads. Default:
+    /// `payload`.
+    #[cfg(feature = "native")]
+    pub fn listener_route(mut self, route: &'static str) -> Self {
+        self.listener_route = route;
+        self
+    }
+
+    /// Sets the webhook secrets. Defaults to the value of the `WEBHOOK_SECRET`
+    /// environment variable.
+    #[cfg(feature = "secrets")]
+    pub fn listener_secrets<S: Into<WebhookSecrets>>(mut self, secrets: S) -> Self {
+        self.listener_secrets = Some(secrets.into());
+        self
+    }
+
+    /// Gets the futures of all routes that match the given event.
+    fn matching(
+        &self,
+        github_client: &Arc<Client<Self>>,
+        ctx: &WebhookContext,
+        event: &WebhookEvent,
+    ) -> Vec<BoxFuture<Command<M>>> {
+        let event_type = event.event_type();
+        let mut payload = None;
+
+        self.routes
+            .iter()
+            .filter(|route| route.event_type == event_type)
+            .filter(|route| {
+                route.filter.is_empty()
+                    || route.filter.matches(
+                        payload.get_or_insert_with(|| serde_json::from_slice(&ctx.raw_body).unwrap_or_default()),
+                    )
+            })
+            .filter_map(|route| (route.handler)(github_client, event))
+            .collect()
+    }
+}
+
+impl<M> Default for Router<M>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    fn default() -> Self {
+        Self {
+            routes: Vec::new(),
+            message_handler: None,
+            #[cfg(feature = "native")]
+            listener_port: 8080,
+            #[cfg(feature = "native")]
+            listener_route: "payload",
+            #[cfg(feature = "secrets")]
+            listener_secrets: None,
+        }
+    }
+}
+
+impl<M> fmt::Debug for Router<M>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router").field("routes", &self.routes.len()).finish()
+    }
+}
+
+#[async_trait]
+impl<M> EventHandler for Router<M>
+where
+    M: fmt::Debug + Send + 'static,
+{
+    type Message = M;
+    type GitHubClient = Client<Self>;
+
+    #[cfg(feature = "native")]
+    fn listener_port(&self) -> u16 {
+        self.listener_port
+    }
+
+    #[cfg(feature = "native")]
+    fn route(&self) -> &'static str {
+        self.listener_route
+    }
+
+    #[cfg(feature = "secrets")]
+    fn listener_secrets(&self) -> WebhookSecrets {
+        match &self.listener_secrets {
+            Some(secrets) => secrets.clone(),
+            None => WebhookSecrets::from_env("WEBHOOK_SECRET").unwrap_or_default(),
+        }
+    }
+
+    async fn message(&self, message: Self::Message) {
+        if let Some(f) = &self.message_handler {
+            f(message);
+        }
+    }
+
+    async fn on_event(
+        &self,
+        github_client: Arc<Self::GitHubClient>,
+        ctx: WebhookContext,
+        event: WebhookEvent,
+    ) -> Command<Self::Message> {
+        let futures = self.matching(&github_client, &ctx, &event);
+
+        Command::perform_multiple(future::join_all(futures).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use serde_json::json;
+
+    use github_rest::model::{
+        issues::events::{IssueAction, IssueEvent},
+        repositories::events::PushEvent,
+    };
+
+    use super::*;
+
+    fn context(body: &Value) -> WebhookContext {
+        let body = serde_json::to_vec(body).unwrap();
+
+        WebhookContext::from_headers(|_| Some("push".to_owned()), &body, SystemTime::now()).unwrap()
+    }
+
+    #[test]
+    fn test_filter() {
+        let payload = json!({
+            "ref": "refs/heads/main",
+            "repository": { "full_name": "octocat-rs/octocat-rs" },
+            "sender": { "type": "Bot" },
+        });
+
+        let filter = |repository: Option<&str>, sender_type, branch: Option<&str>| Filter {
+            repository: repository.map(ToOwned::to_owned),
+            sender_type,
+            branch: branch.map(ToOwned::to_owned),
+        };
+
+        assert!(filter(None, None, None).matches(&payload));
+        assert!(filter(Some("Octocat-rs/octocat-rs"), Some(SenderType::Bot), Some("main")).matches(&payload));
+        assert!(filter(None, None, Some("refs/heads/main")).matches(&payload));
+        assert!(!filter(Some("octocat-rs/other"), None, None).matches(&payload));
+        assert!(!filter(None, Some(SenderType::User), None).matches(&payload));
+        assert!(!filter(None, None, Some("dev")).matches(&payload));
+
+        assert_eq!(branch_of(&json!({ "ref": "refs/tags/v1.0.0" })), None);
+        assert_eq!(branch_of(&json!({ "ref": "dev", "ref_type": "branch" })), Some("dev"));
+        assert_eq!(
+            branch_of(&json!({ "pull_request": { "base": { "ref": "main" } } })),
+            Some("main")
+        );
+    }
+
+    #[test]
+    fn test_matching() {
+        let router = Router::new()
+            .on_any(|_, _: PushEvent| async { Command::none() })
+            .on(IssueAction::Opened, |_, _: IssueEvent| async { Command::none() })
+            .add_route(Route::new(|_, _: PushEvent| async { Command::none() }).branch("dev"));
+        let client = Arc::new(Client::new(Router::new(), None, None, None));
+
+        let matching = |git_ref: &str| {
+            let push = PushEvent {
+                ref_field: git_ref.to_owned(),
+                ..PushEvent::default()
+            };
+            let ctx = context(&serde_json::to_value(&push).unwrap());
+
+            router.matching(&client, &ctx, &WebhookEvent::Push(push)).len()
+        };
+
+        assert_eq!(matching("refs/heads/main"), 1);
+        assert_eq!(matching("refs/heads/dev"), 2);
+    }
+}
@@ -0,0 +1,186 @@
+//! Contains [`WebhookSecrets`], used to verify the signatures of webhook
+//! deliveries.
+
+use std::{env, ffi::OsStr, fmt};
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::Sha256;
+
+pub(crate) const GITHUB_SIGNATURE_256_HEADER: &str = "X-Hub-Signature-256";
+pub(crate) const GITHUB_SIGNATURE_HEADER: &str = "X-Hub-Signature";
+
+/// The secrets a webhook delivery may be signed with. A delivery is accepted
+/// if its signature matches any of them, which allows for rotating secrets
+/// without downtime: add the new secret, update it on GitHub, then remove the
+/// old one.
+///
+/// Empty secrets are ignored, and a set without any secrets doesn't match any
+/// signature. Use [`WebhookSecrets::insecure_allow_all`] to accept deliveries
+/// without checking their signature.
+///
+/// ```rust,ignore
+/// let secrets = WebhookSecrets::from_env("WEBHOOK_SECRET")?.with_secret("previous secret");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct WebhookSecrets {
+    secrets: Vec<Vec<u8>>,
+    allow_all: bool,
+}
+
+impl WebhookSecrets {
+    /// Creates a [`WebhookSecrets`] instance without any secrets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`WebhookSecrets`] instance that accepts every delivery,
+    /// whether it is signed or not. Only use this if deliveries are
+    /// authenticated some other way, or for testing.
+    pub fn insecure_allow_all() -> Self {
+        Self {
+            allow_all: true,
+            ..Self::default()
+        }
+    }
+
+    /// Reads a secret from the given environment variable.
+    pub fn from_env<K: AsRef<OsStr>>(key: K) -> Result<Self, env::VarError> {
+        Ok(Self::from(env::var(key)?))
+    }
+
+    /// Adds a secret to the current set. Empty secrets are ignored.
+    pub fn with_secret<V: Into<Vec<u8>>>(mut self, secret: V) -> Self {
+        let secret = secret.into();
+
+        if !secret.is_empty() {
+            self.secrets.push(secret);
+        }
+
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.secrets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Checks the value of an `X-Hub-Signature-256` header, e.g.
+    /// `sha256=757107ea...`.
+    pub fn verify_sha256(&self, body: &[u8], signature: &str) -> bool {
+        self.verify::<Hmac<Sha256>>(body, signature.trim().trim_start_matches("sha256="))
+    }
+
+    /// Checks the value of the legacy `X-Hub-Signature` header, e.g.
+    /// `sha1=01dc10d0...`.
+    pub fn verify_sha1(&self, body: &[u8], signature: &str) -> bool {
+        self.verify::<Hmac<Sha1>>(body, signature.trim().trim_start_matches("sha1="))
+    }
+
+    fn verify<M: Mac + hmac::digest::KeyInit>(&self, body: &[u8], signature: &str) -> bool {
+        if self.allow_all {
+            return true;
+        }
+
+        let hash = hex::decode(signature).ok();
+
+        // Every secret is tried, so that the time taken doesn't reveal which
+        // one matched
+        self.secrets.iter().fold(false, |valid, secret| {
+            let matches = !secret.is_empty()
+                && hash.as_ref().is_some_and(|hash| {
+                    let mut mac = <M as Mac>::new_from_slice(secret).expect("HMAC can take a key of any size");
+                    mac.update(body);
+                    mac.verify_slice(hash).is_ok()
+                });
+
+            valid | matches
+        })
+    }
+}
+
+impl fmt::Debug for WebhookSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSecrets")
+            .field("secrets", &format_args!("[{} redacted]", self.secrets.len()))
+            .field("allow_all", &self.allow_all)
+            .finish()
+    }
+}
+
+impl From<&str> for WebhookSecrets {
+    fn from(secret: &str) -> Self {
+        Self::new().with_secret(secret)
+    }
+}
+
+impl From<String> for WebhookSecrets {
+    fn from(secret: String) -> Self {
+        Self::new().with_secret(secret)
+    }
+}
+
+impl From<&[u8]> for WebhookSecrets {
+    fn from(secret: &[u8]) -> Self {
+        Self::new().with_secret(secret)
+    }
+}
+
+impl<V: Into<Vec<u8>>> FromIterator<V> for WebhookSecrets {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::with_secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_signature() {
+        // Example from https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+        let secrets = WebhookSecrets::from("It's a Secret to Everybody");
+
+        assert!(secrets.verify_sha256(b"Hello, World!", signature));
+        assert!(!secrets.verify_sha256(b"Hello, World?", signature));
+        assert!(!secrets.verify_sha256(b"Hello, World!", "sha256=zz"));
+        assert!(!WebhookSecrets::from("").verify_sha256(b"Hello, World?", ""));
+        assert!(WebhookSecrets::insecure_allow_all().verify_sha256(b"Hello, World?", ""));
+    }
+
+    #[test]
+    fn test_multiple_secrets() {
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+        let legacy = "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59";
+
+        let secrets: WebhookSecrets = ["new secret", "It's a Secret to Everybody"].into_iter().collect();
+
+        assert!(secrets.verify_sha256(b"Hello, World!", signature));
+        assert!(secrets.verify_sha1(b"Hello, World!", legacy));
+        assert!(!secrets.verify_sha1(b"Hello, World!", signature));
+        assert!(!WebhookSecrets::new().verify_sha256(b"Hello, World!", signature));
+        assert_eq!(
+            format!("{secrets:?}"),
+            "WebhookSecrets { secrets: [2 redacted], allow_all: false }"
+        );
+    }
+
+    #[test]
+    fn test_empty_secret_in_set() {
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+        let secrets = WebhookSecrets::from("It's a Secret to Everybody").with_secret("");
+        let collected: WebhookSecrets = ["", "It's a Secret to Everybody"].into_iter().collect();
+
+        for secrets in [secrets, collected] {
+            assert_eq!(secrets.len(), 1);
+            assert!(secrets.verify_sha256(b"Hello, World!", signature));
+            assert!(!secrets.verify_sha256(b"Hello, World?", signature));
+            assert!(!secrets.verify_sha256(b"Hello, World?", ""));
+        }
+    }
+}
@@ -32,7 +32,28 @@ impl OctocatConfig {
 /// [`Client`]: crate::github::Client
 #[derive(Clone)]
 pub enum Authorization {
+    /// Sent using Basic auth.
     PersonalToken { username: String, token: String },
+    /// Sent as `Authorization: Bearer <token>`. Use this for fine-grained
+    /// personal access tokens and OAuth tokens.
+    ///
+    /// See also: [`OAuthApp`]
+    ///
+    /// [`OAuthApp`]: crate::github::OAuthApp
+    Bearer(String),
+    /// Authenticates as a GitHub App using a JWT signed with the App's private
+    /// key. Only a few endpoints, mostly under `/app`, can be used this way.
+    #[cfg(feature = "apps")]
+    App { app_id: u64, private_key_pem: String },
+    /// Authenticates as an installation of a GitHub App. An installation
+    /// access token is requested using the App's credentials, and replaced
+    /// automatically before it expires.
+    #[cfg(feature = "apps")]
+    Installation {
+        app_id: u64,
+        private_key_pem: String,
+        installation_id: u64,
+    },
 }
 
 impl Default for Authorization {
@@ -0,0 +1,216 @@
+//! Contains [`WebhookContext`], describing a single webhook delivery,
+//! [`WebhookStatus`], describing how the webhook listener answered it,
+//! [`ListenerHandle`], used to control a listener running in the
+//! background, and [`TlsConfig`], used to serve the listener over HTTPS.
+
+#[cfg(feature = "tls")]
+use std::path::PathBuf;
+use std::{fmt, sync::Arc, time::SystemTime};
+#[cfg(feature = "native")]
+use std::{net::SocketAddr, time::Duration};
+
+#[cfg(feature = "native")]
+use tokio::{sync::oneshot, task::JoinHandle};
+
+/// The default amount of time the listener waits for pending deliveries when
+/// shutting down.
+#[cfg(feature = "native")]
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) const GITHUB_EVENT_HEADER: &str = "X-GitHub-Event";
+const GITHUB_DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+const GITHUB_HOOK_ID_HEADER: &str = "X-GitHub-Hook-ID";
+const GITHUB_TARGET_TYPE_HEADER: &str = "X-GitHub-Hook-Installation-Target-Type";
+const GITHUB_TARGET_ID_HEADER: &str = "X-GitHub-Hook-Installation-Target-ID";
+
+/// Information about a webhook delivery that isn't part of its payload.
+///
+/// Passed to the `*_with_context` methods of [`EventHandler`].
+///
+/// [`EventHandler`]: crate::github::handler::EventHandler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookContext {
+    /// The GUID sent in the `X-GitHub-Delivery` header. Redeliveries reuse
+    /// the GUID of the original delivery.
+    pub delivery_id: Option<String>,
+    /// The name of the event, sent in the `X-GitHub-Event` header.
+    pub event_type: String,
+    /// The ID of the webhook, sent in the `X-GitHub-Hook-ID` header.
+    pub hook_id: Option<u64>,
+    /// The type of resource the webhook was created on, e.g. `repository`,
+    /// `organization` or `integration`. Sent in the
+    /// `X-GitHub-Hook-Installation-Target-Type` header.
+    pub installation_target_type: Option<String>,
+    /// The ID of the resource the webhook was created on, sent in the
+    /// `X-GitHub-Hook-Installation-Target-ID` header.
+    pub installation_target_id: Option<u64>,
+    /// The payload, as it was received.
+    pub raw_body: Arc<[u8]>,
+    /// When the delivery was received by the listener.
+    pub received_at: SystemTime,
+}
+
+impl WebhookContext {
+    /// Creates a [`WebhookContext`] from the headers of a delivery, using
+    /// `header` to look them up. Returns `None` if the `X-GitHub-Event`
+    /// header is missing.
+    pub(crate) fn from_headers(
+        header: impl Fn(&str) -> Option<String>,
+        raw_body: &[u8],
+        received_at: SystemTime,
+    ) -> Option<Self> {
+        Some(Self {
+            delivery_id: header(GITHUB_DELIVERY_HEADER),
+            event_type: header(GITHUB_EVENT_HEADER)?,
+            hook_id: header(GITHUB_HOOK_ID_HEADER).and_then(|id| id.parse().ok()),
+            installation_target_type: header(GITHUB_TARGET_TYPE_HEADER),
+            installation_target_id: header(GITHUB_TARGET_ID_HEADER).and_then(|id| id.parse().ok()),
+            raw_body: raw_body.into(),
+            received_at,
+        })
+    }
+}
+
+/// The outcome of a webhook delivery, which determines the status code of the
+/// response sent back to GitHub. The body of the response can be customized
+/// through [`EventHandler::response_body`].
+///
+/// [`EventHandler::response_body`]: crate::github::handler::EventHandler::response_body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookStatus {
+    /// The payload was passed on to the event handler (`202 Accepted`).
+    Accepted,
+    /// Both the `X-Hub-Signature-256` and the legacy `X-Hub-Signature`
+    /// header are missing (`401 Unauthorized`).
+    MissingSignature,
+    /// The signature doesn't match the payload (`403 Forbidden`).
+    InvalidSignature,
+    /// The `X-GitHub-Event` header is missing, or the payload could not be
+    /// read or parsed (`400 Bad Request`).
+    InvalidPayload,
+    /// The delivery has already been handled, according to the configured
+    /// [`IdempotencyStore`] (`200 OK`).
+    ///
+    /// [`IdempotencyStore`]: crate::github::IdempotencyStore
+    Duplicate,
+}
+
+impl WebhookStatus {
+    /// Gets the HTTP status code of the response.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookStatus::Accepted => 202,
+            WebhookStatus::MissingSignature => 401,
+            WebhookStatus::InvalidSignature => 403,
+            WebhookStatus::InvalidPayload => 400,
+            WebhookStatus::Duplicate => 200,
+        }
+    }
+
+    /// Whether the payload was passed on to the event handler.
+    pub fn is_accepted(&self) -> bool {
+        *self == WebhookStatus::Accepted
+    }
+}
+
+impl fmt::Display for WebhookStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WebhookStatus::Accepted => "Accepted",
+            WebhookStatus::MissingSignature => "Missing X-Hub-Signature-256 header",
+            WebhookStatus::InvalidSignature => "Signatures don't match",
+            WebhookStatus::InvalidPayload => "Invalid payload",
+            WebhookStatus::Duplicate => "Duplicate delivery",
+        })
+    }
+}
+
+/// The certificate and private key used by the listener to terminate TLS.
+/// Both files have to be PEM encoded.
+#[cfg(feature = "tls")]
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    pub fn new<P: Into<PathBuf>>(cert_path: P, key_path: P) -> Self {
+        Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+        }
+    }
+}
+
+/// A handle to a listener started with [`Client::listen`].
+///
+/// Dropping the handle doesn't stop the listener.
+///
+/// [`Client::listen`]: crate::github::Client::listen
+#[cfg(feature = "native")]
+#[derive(Debug)]
+pub struct ListenerHandle {
+    local_addr: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+#[cfg(feature = "native")]
+impl ListenerHandle {
+    pub(crate) fn new(local_addr: SocketAddr, shutdown: oneshot::Sender<()>, task: JoinHandle<()>) -> Self {
+        Self {
+            local_addr,
+            shutdown,
+            task,
+        }
+    }
+
+    /// Gets the address the listener is bound to. Unlike
+    /// [`Client::bind_addr`], this contains the actual port if port 0 was
+    /// used.
+    ///
+    /// [`Client::bind_addr`]: crate::github::Client::bind_addr
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops accepting new deliveries, then waits for the listener to finish
+    /// the pending ones.
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.task.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_from_headers() {
+        let headers = [
+            ("X-GitHub-Event", "issues"),
+            ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+            ("X-GitHub-Hook-ID", "292430182"),
+            ("X-GitHub-Hook-Installation-Target-Type", "repository"),
+            ("X-GitHub-Hook-Installation-Target-ID", "79929171"),
+        ];
+        let header = |name: &str| headers.iter().find(|(h, _)| *h == name).map(|(_, v)| v.to_string());
+
+        let ctx = WebhookContext::from_headers(header, b"{}", SystemTime::UNIX_EPOCH).unwrap();
+
+        assert_eq!(ctx.event_type, "issues");
+        assert_eq!(ctx.delivery_id.as_deref(), Some("72d3162e-cc78-11e3-81ab-4c9367dc0958"));
+        assert_eq!(ctx.hook_id, Some(292430182));
+        assert_eq!(ctx.installation_target_type.as_deref(), Some("repository"));
+        assert_eq!(ctx.installation_target_id, Some(79929171));
+        assert_eq!(&*ctx.raw_body, b"{}");
+
+        assert_eq!(
+            WebhookContext::from_headers(|_| None, b"{}", SystemTime::UNIX_EPOCH),
+            None
+        );
+    }
+}
@@ -17,6 +17,11 @@ pub use github_rest as rest;
 #[cfg(all(feature = "native", feature = "workers"))]
 compile_error!("feature \"native\" and feature \"workers\" cannot be enabled at the same time");
 
+// `HttpClient` has no way of sending requests otherwise, and would recurse
+// between `raw_req_with_meta` and `raw_req_with_headers` forever.
+#[cfg(not(any(feature = "native", all(target_family = "wasm", feature = "workers"))))]
+compile_error!("either feature \"native\", or feature \"workers\" on a wasm target, must be enabled");
+
 #[cfg(test)]
 mod tests {
     use crate::{github::ClientBuilder, DefaultEventHandler};
@@ -56,8 +61,8 @@ mod tests {
             }
 
             #[cfg(feature = "secrets")]
-            fn listener_secret(&self) -> &'static [u8] {
-                "".as_bytes()
+            fn listener_secrets(&self) -> crate::WebhookSecrets {
+                crate::WebhookSecrets::insecure_allow_all()
             }
 
             async fn message(&self, _message: Self::Message) {}